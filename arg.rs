@@ -0,0 +1,109 @@
+use crate::{MemorySize, MemorySizeError};
+
+/// Parses sizes from command line arguments, checking them against constraints
+/// 
+/// Created by `MemorySize::arg_parser`, then configured by chaining the constraint methods
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::MemorySize;
+///
+/// let parser = MemorySize::arg_parser()
+///     .min(MemorySize::from_bytes(1 << 20))
+///     .max(MemorySize::from_bytes(16 << 30))
+///     .alignment(MemorySize::from_bytes(4096));
+///
+/// assert_eq!(parser.parse("8 MiB"), Ok(MemorySize::from_bytes(8 << 20)));
+/// assert!(parser.parse("1 KiB").is_err());
+/// assert!(parser.parse("8388609").is_err());
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SizeArgParser {
+    min: MemorySize,
+    max: MemorySize,
+    alignment: Option<MemorySize>,
+    whole_bytes: bool,
+}
+
+impl MemorySize {
+    /// Creates a parser for command line arguments that accepts any size
+    pub const fn arg_parser() -> SizeArgParser {
+        SizeArgParser {
+            min: MemorySize::new(),
+            max: MemorySize::from_bits(u64::MAX),
+            alignment: None,
+            whole_bytes: false,
+        }
+    }
+}
+
+impl SizeArgParser {
+    /// Rejects sizes smaller than `min`
+    pub const fn min(self, min: MemorySize) -> Self {
+        SizeArgParser { min, ..self }
+    }
+
+    /// Rejects sizes larger than `max`
+    pub const fn max(self, max: MemorySize) -> Self {
+        SizeArgParser { max, ..self }
+    }
+
+    /// Rejects sizes that are not a multiple of `alignment`, replacing any previous alignment
+    /// 
+    /// This is kept separate from `whole_bytes`, so both constraints apply whichever order they are set in
+    /// 
+    /// # Panics
+    /// if `alignment` is zero, or `whole_bytes` is set and the smallest whole number of bytes that `alignment` divides overflows
+    pub const fn alignment(self, alignment: MemorySize) -> Self {
+        assert!(alignment.size_bits() != 0, "alignment must be non-zero");
+        let parser = SizeArgParser { alignment: Some(alignment), ..self };
+        parser.required_alignment();
+        parser
+    }
+
+    /// Rejects sizes that are not a whole number of bytes, as well as any that break the alignment
+    /// 
+    /// # Panics
+    /// if the smallest whole number of bytes that the alignment divides overflows
+    pub const fn whole_bytes(self) -> Self {
+        let parser = SizeArgParser { whole_bytes: true, ..self };
+        parser.required_alignment();
+        parser
+    }
+
+    /// Combines the alignment and whole byte constraints into the single alignment that sizes must be a multiple of
+    const fn required_alignment(&self) -> Option<MemorySize> {
+        match (self.alignment, self.whole_bytes) {
+            (Some(alignment), true) => Some(MemorySize::from_bits(crate::lcm(alignment.size_bits(), crate::BITS_IN_BYTE))),
+            (Some(alignment), false) => Some(alignment),
+            (None, true) => Some(MemorySize::from_bytes(1)),
+            (None, false) => None,
+        }
+    }
+
+    /// Checks that `size` meets every constraint
+    /// 
+    /// # Errors
+    /// `MemorySizeError::OutOfBounds` if `size` is below the minimum or above the maximum,
+    /// and `MemorySizeError::Unaligned` if it is not a multiple of the alignment
+    pub const fn check(&self, size: MemorySize) -> Result<MemorySize, MemorySizeError> {
+        if size.size_bits() < self.min.size_bits() || size.size_bits() > self.max.size_bits() {
+            return Err(MemorySizeError::OutOfBounds { size, min: self.min, max: self.max });
+        }
+        if let Some(alignment) = self.required_alignment() {
+            if !size.divides_evenly(alignment) {
+                return Err(MemorySizeError::Unaligned { size, alignment });
+            }
+        }
+        Ok(size)
+    }
+
+    /// Parses `arg` in the same way as `MemorySize::from_str`, then checks it meets every constraint
+    /// 
+    /// # Errors
+    /// any error from parsing or from `check`
+    pub fn parse(&self, arg: &str) -> Result<MemorySize, MemorySizeError> {
+        self.check(arg.parse()?)
+    }
+}
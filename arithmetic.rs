@@ -0,0 +1,700 @@
+use crate::{MemorySize, MemorySizeError, RoundingMode};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, Shl, Shr, Sub, SubAssign};
+
+impl MemorySize {
+    /// Adds `rhs` to `&self` in a `const` context, where the `+` operator cannot be used
+    /// 
+    /// # Panics
+    /// if the result overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// const HEADER: MemorySize = MemorySize::from_bytes(16);
+    /// const RECORD: MemorySize = HEADER.const_add(MemorySize::from_bytes(48));
+    /// assert_eq!(RECORD, MemorySize::from_bytes(64));
+    /// ```
+    pub const fn const_add(&self, rhs: MemorySize) -> MemorySize {
+        self.checked_add(rhs).unwrap()
+    }
+
+    /// Subtracts `rhs` from `&self` in a `const` context, where the `-` operator cannot be used
+    /// 
+    /// # Panics
+    /// if `rhs` is larger than `&self`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// const PAYLOAD: MemorySize = MemorySize::from_bytes(64).const_sub(MemorySize::from_bytes(16));
+    /// assert_eq!(PAYLOAD, MemorySize::from_bytes(48));
+    /// ```
+    pub const fn const_sub(&self, rhs: MemorySize) -> MemorySize {
+        self.checked_sub(rhs).unwrap()
+    }
+
+    /// Returns the larger of `&self` and `other` in a `const` context, where `Ord::max` cannot be used
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// const SLOT: MemorySize = MemorySize::from_bytes(24).const_max(MemorySize::from_bytes(32));
+    /// assert_eq!(SLOT, MemorySize::from_bytes(32));
+    /// ```
+    pub const fn const_max(&self, other: MemorySize) -> MemorySize {
+        if self.size_bits >= other.size_bits {*self} else {other}
+    }
+
+    /// Returns the smaller of `&self` and `other` in a `const` context, where `Ord::min` cannot be used
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// const SLOT: MemorySize = MemorySize::from_bytes(24).const_min(MemorySize::from_bytes(32));
+    /// assert_eq!(SLOT, MemorySize::from_bytes(24));
+    /// ```
+    pub const fn const_min(&self, other: MemorySize) -> MemorySize {
+        if self.size_bits <= other.size_bits {*self} else {other}
+    }
+
+    /// Compares `&self` with `other` in a `const` context, where `Ord::cmp` cannot be used
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use memory_size::MemorySize;
+    ///
+    /// const ORDER: Ordering = MemorySize::from_bytes(1).const_cmp(MemorySize::from_bits(9));
+    /// assert_eq!(ORDER, Ordering::Less);
+    /// ```
+    pub const fn const_cmp(&self, other: MemorySize) -> core::cmp::Ordering {
+        if self.size_bits < other.size_bits {
+            core::cmp::Ordering::Less
+        } else if self.size_bits > other.size_bits {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }
+
+    /// Returns whether `&self` equals `other` in a `const` context, where `==` cannot be used
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// const SAME: bool = MemorySize::from_bytes(1).const_eq(MemorySize::from_bits(8));
+    /// assert!(SAME);
+    /// ```
+    pub const fn const_eq(&self, other: MemorySize) -> bool {
+        self.size_bits == other.size_bits
+    }
+
+    /// Adds `rhs` to `&self`
+    /// 
+    /// Returns `None` if the result overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bits(1).checked_add(MemorySize::from_bits(2)), Some(MemorySize::from_bits(3)));
+    /// assert_eq!(MemorySize::from_bits(u64::MAX).checked_add(MemorySize::from_bits(1)), None);
+    /// ```
+    pub const fn checked_add(&self, rhs: MemorySize) -> Option<MemorySize> {
+        match self.size_bits.checked_add(rhs.size_bits) {
+            Some(size_bits) => Some(MemorySize { size_bits }),
+            None => None,
+        }
+    }
+
+    /// Subtracts `rhs` from `&self`
+    /// 
+    /// Returns `None` if `rhs` is larger than `&self`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bits(3).checked_sub(MemorySize::from_bits(2)), Some(MemorySize::from_bits(1)));
+    /// assert_eq!(MemorySize::from_bits(2).checked_sub(MemorySize::from_bits(3)), None);
+    /// ```
+    pub const fn checked_sub(&self, rhs: MemorySize) -> Option<MemorySize> {
+        match self.size_bits.checked_sub(rhs.size_bits) {
+            Some(size_bits) => Some(MemorySize { size_bits }),
+            None => None,
+        }
+    }
+
+    /// Multiplies `&self` by `rhs`
+    /// 
+    /// Returns `None` if the result overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(4).checked_mul(3), Some(MemorySize::from_bytes(12)));
+    /// assert_eq!(MemorySize::from_bits(u64::MAX).checked_mul(2), None);
+    /// ```
+    pub const fn checked_mul(&self, rhs: u64) -> Option<MemorySize> {
+        match self.size_bits.checked_mul(rhs) {
+            Some(size_bits) => Some(MemorySize { size_bits }),
+            None => None,
+        }
+    }
+
+    /// Calculates how many blocks of size `block` are needed to hold `&self`
+    /// 
+    /// # Panics
+    /// if `block` is zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let block = MemorySize::from_bytes(4096);
+    /// assert_eq!(MemorySize::from_bytes(4097).div_ceil(block), 2);
+    /// assert_eq!(MemorySize::from_bytes(4096).div_ceil(block), 1);
+    /// ```
+    pub const fn div_ceil(&self, block: MemorySize) -> u64 {
+        self.size_bits.div_ceil(block.size_bits)
+    }
+
+    /// Calculates the size left over after taking as many whole `rhs` out of `&self` as possible
+    /// 
+    /// Returns `None` if `rhs` is zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let chunk = MemorySize::from_bytes(4096);
+    /// assert_eq!(MemorySize::from_bytes(10_000).checked_rem(chunk), Some(MemorySize::from_bytes(1808)));
+    /// assert_eq!(MemorySize::from_bytes(10_000).checked_rem(MemorySize::new()), None);
+    /// ```
+    pub const fn checked_rem(&self, rhs: MemorySize) -> Option<MemorySize> {
+        match self.size_bits.checked_rem(rhs.size_bits) {
+            Some(size_bits) => Some(MemorySize { size_bits }),
+            None => None,
+        }
+    }
+
+    /// Calculates the difference between `&self` and `other`, whichever is larger
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let (expected, actual) = (MemorySize::from_bytes(100), MemorySize::from_bytes(96));
+    /// assert_eq!(expected.abs_diff(actual), MemorySize::from_bytes(4));
+    /// assert_eq!(actual.abs_diff(expected), MemorySize::from_bytes(4));
+    /// ```
+    pub const fn abs_diff(&self, other: MemorySize) -> MemorySize {
+        MemorySize { size_bits: self.size_bits.abs_diff(other.size_bits) }
+    }
+
+    /// Multiplies `&self` by `2^rhs`
+    /// 
+    /// Returns `None` if the result overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(3).checked_shl(2), Some(MemorySize::from_bytes(12)));
+    /// assert_eq!(MemorySize::from_bits(1 << 63).checked_shl(1), None);
+    /// ```
+    pub const fn checked_shl(&self, rhs: u32) -> Option<MemorySize> {
+        if rhs >= u64::BITS {
+            return if self.size_bits == 0 {Some(*self)} else {None};
+        }
+        if self.size_bits.leading_zeros() < rhs {
+            return None;
+        }
+        Some(MemorySize { size_bits: self.size_bits << rhs })
+    }
+
+    /// Divides `&self` by `2^rhs`, rounding down to the nearest bit
+    /// 
+    /// Returns `None` if `rhs` is 64 or more, like `u64::checked_shr`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(12).checked_shr(2), Some(MemorySize::from_bytes(3)));
+    /// assert_eq!(MemorySize::from_bytes(12).checked_shr(64), None);
+    /// ```
+    pub const fn checked_shr(&self, rhs: u32) -> Option<MemorySize> {
+        match self.size_bits.checked_shr(rhs) {
+            Some(size_bits) => Some(MemorySize { size_bits }),
+            None => None,
+        }
+    }
+
+    /// Adds `rhs` to `&self`, giving the largest size instead of overflowing
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let max = MemorySize::from_bits(u64::MAX);
+    /// assert_eq!(MemorySize::from_bits(1).saturating_add(MemorySize::from_bits(2)), MemorySize::from_bits(3));
+    /// assert_eq!(max.saturating_add(MemorySize::from_bits(1)), max);
+    /// ```
+    pub const fn saturating_add(&self, rhs: MemorySize) -> MemorySize {
+        MemorySize { size_bits: self.size_bits.saturating_add(rhs.size_bits) }
+    }
+
+    /// Subtracts `rhs` from `&self`, giving zero instead of a negative size
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let free = MemorySize::from_bytes(100);
+    /// assert_eq!(free.saturating_sub(MemorySize::from_bytes(30)), MemorySize::from_bytes(70));
+    /// assert_eq!(free.saturating_sub(MemorySize::from_bytes(300)), MemorySize::new());
+    /// ```
+    pub const fn saturating_sub(&self, rhs: MemorySize) -> MemorySize {
+        MemorySize { size_bits: self.size_bits.saturating_sub(rhs.size_bits) }
+    }
+
+    /// Multiplies `&self` by `rhs`, giving the largest size instead of overflowing
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(4).saturating_mul(3), MemorySize::from_bytes(12));
+    /// assert_eq!(MemorySize::from_bits(u64::MAX).saturating_mul(2), MemorySize::from_bits(u64::MAX));
+    /// ```
+    pub const fn saturating_mul(&self, rhs: u64) -> MemorySize {
+        MemorySize { size_bits: self.size_bits.saturating_mul(rhs) }
+    }
+
+    /// Adds `rhs` to `&self`, wrapping around at the largest size
+    /// 
+    /// Returns the wrapped size, and whether it overflowed
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bits(1).overflowing_add(MemorySize::from_bits(2)), (MemorySize::from_bits(3), false));
+    /// assert_eq!(MemorySize::from_bits(u64::MAX).overflowing_add(MemorySize::from_bits(2)), (MemorySize::from_bits(1), true));
+    /// ```
+    pub const fn overflowing_add(&self, rhs: MemorySize) -> (MemorySize, bool) {
+        let (size_bits, overflowed) = self.size_bits.overflowing_add(rhs.size_bits);
+        (MemorySize { size_bits }, overflowed)
+    }
+
+    /// Subtracts `rhs` from `&self`, wrapping around at zero
+    /// 
+    /// Returns the wrapped size, and whether it overflowed
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bits(3).overflowing_sub(MemorySize::from_bits(2)), (MemorySize::from_bits(1), false));
+    /// assert_eq!(MemorySize::new().overflowing_sub(MemorySize::from_bits(1)), (MemorySize::from_bits(u64::MAX), true));
+    /// ```
+    pub const fn overflowing_sub(&self, rhs: MemorySize) -> (MemorySize, bool) {
+        let (size_bits, overflowed) = self.size_bits.overflowing_sub(rhs.size_bits);
+        (MemorySize { size_bits }, overflowed)
+    }
+
+    /// Multiplies `&self` by `rhs`, wrapping around at the largest size
+    /// 
+    /// Returns the wrapped size, and whether it overflowed
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bits(3).overflowing_mul(2), (MemorySize::from_bits(6), false));
+    /// assert_eq!(MemorySize::from_bits(1 << 63).overflowing_mul(2), (MemorySize::new(), true));
+    /// ```
+    pub const fn overflowing_mul(&self, rhs: u64) -> (MemorySize, bool) {
+        let (size_bits, overflowed) = self.size_bits.overflowing_mul(rhs);
+        (MemorySize { size_bits }, overflowed)
+    }
+
+    /// Adds `rhs` to `&self`, wrapping around at the largest size
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bits(u64::MAX).wrapping_add(MemorySize::from_bits(2)), MemorySize::from_bits(1));
+    /// ```
+    pub const fn wrapping_add(&self, rhs: MemorySize) -> MemorySize {
+        MemorySize { size_bits: self.size_bits.wrapping_add(rhs.size_bits) }
+    }
+
+    /// Subtracts `rhs` from `&self`, wrapping around at zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::new().wrapping_sub(MemorySize::from_bits(1)), MemorySize::from_bits(u64::MAX));
+    /// ```
+    pub const fn wrapping_sub(&self, rhs: MemorySize) -> MemorySize {
+        MemorySize { size_bits: self.size_bits.wrapping_sub(rhs.size_bits) }
+    }
+
+    /// Multiplies `&self` by `rhs`, wrapping around at the largest size
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bits((1 << 63) + 1).wrapping_mul(2), MemorySize::from_bits(2));
+    /// ```
+    pub const fn wrapping_mul(&self, rhs: u64) -> MemorySize {
+        MemorySize { size_bits: self.size_bits.wrapping_mul(rhs) }
+    }
+
+    /// Calculates how many times `other` fits into `&self`, including the fraction
+    /// 
+    /// Dividing by a zero size gives infinity, or NaN if `&self` is also zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(6144).as_ratio_f64(MemorySize::from_bytes(4096)), 1.5);
+    /// ```
+    pub const fn as_ratio_f64(&self, other: MemorySize) -> f64 {
+        self.size_bits as f64 / other.size_bits as f64
+    }
+
+    /// Multiplies `&self` by `numerator / denominator`, rounding the result to a whole bit with `rounding`
+    /// 
+    /// The calculation is done in 128 bits, so only the result needs to fit in a `MemorySize`
+    /// 
+    /// # Panics
+    /// if `denominator` is zero, or the result overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, RoundingMode};
+    ///
+    /// let limit = MemorySize::from_bytes(1 << 30);
+    /// assert_eq!(limit.scale(3, 4, RoundingMode::Floor), MemorySize::from_bytes(3 << 28));
+    /// assert_eq!(MemorySize::from_bits(100).scale(2, 3, RoundingMode::Floor), MemorySize::from_bits(66));
+    /// assert_eq!(MemorySize::from_bits(100).scale(2, 3, RoundingMode::Nearest), MemorySize::from_bits(67));
+    /// ```
+    pub const fn scale(&self, numerator: u64, denominator: u64, rounding: RoundingMode) -> MemorySize {
+        assert!(denominator != 0, "cannot scale with a zero denominator");
+        let scaled = rounding.div(self.size_bits as u128 * numerator as u128, denominator as u128);
+        assert!(scaled <= u64::MAX as u128, "scaled size overflowed");
+        MemorySize { size_bits: scaled as u64 }
+    }
+
+    /// Multiplies `&self` by `factor`, rounding the result to a whole bit with `rounding`
+    /// 
+    /// The size is converted to an `f64` first, so sizes above 2^53 bits may lose precision
+    /// 
+    /// # Errors
+    /// - `MemorySizeError::InvalidFactor` if `factor` is NaN or infinite
+    /// - `MemorySizeError::Negative` if the result is negative
+    /// - `MemorySizeError::Overflow` if the result does not fit in a `MemorySize`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, MemorySizeError, RoundingMode};
+    ///
+    /// let capacity = MemorySize::from_bytes(10);
+    /// assert_eq!(capacity.mul_f64(1.5, RoundingMode::Nearest), Ok(MemorySize::from_bytes(15)));
+    /// assert_eq!(MemorySize::from_bits(3).mul_f64(0.5, RoundingMode::Ceil), Ok(MemorySize::from_bits(2)));
+    /// assert_eq!(capacity.mul_f64(-1.0, RoundingMode::Nearest), Err(MemorySizeError::Negative));
+    /// ```
+    pub fn mul_f64(&self, factor: f64, rounding: RoundingMode) -> Result<MemorySize, MemorySizeError> {
+        if !factor.is_finite() {
+            return Err(MemorySizeError::InvalidFactor);
+        }
+        let scaled = self.size_bits as f64 * factor;
+        if scaled < 0.0 {
+            return Err(MemorySizeError::Negative);
+        }
+        //2^64 is the first value that does not fit
+        if scaled >= 18_446_744_073_709_551_616.0 {
+            return Err(MemorySizeError::Overflow);
+        }
+
+        //the cast rounds down, and the fraction is exact as every f64 above 2^53 is whole
+        let floor = scaled as u64;
+        let fraction = scaled - floor as f64;
+        let round_up = match rounding {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => fraction > 0.0,
+            RoundingMode::Nearest => fraction >= 0.5,
+        };
+        floor.checked_add(round_up as u64)
+            .map(MemorySize::from_bits)
+            .ok_or(MemorySizeError::Overflow)
+    }
+}
+
+impl Mul<u64> for MemorySize {
+    type Output = MemorySize;
+
+    /// Calculates the size of `rhs` items that are each of size `&self`
+    /// 
+    /// # Panics
+    /// if the result overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(12) * 10, MemorySize::from_bytes(120));
+    /// ```
+    fn mul(self, rhs: u64) -> MemorySize {
+        self.checked_mul(rhs).unwrap()
+    }
+}
+
+impl Mul<MemorySize> for u64 {
+    type Output = MemorySize;
+
+    /// Calculates the size of `self` items that are each of size `rhs`
+    /// 
+    /// # Panics
+    /// if the result overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(3 * MemorySize::from_bytes(12), MemorySize::from_bytes(36));
+    /// ```
+    fn mul(self, rhs: MemorySize) -> MemorySize {
+        rhs * self
+    }
+}
+
+impl Div<u64> for MemorySize {
+    type Output = MemorySize;
+
+    /// Divides `&self` into `rhs` parts, rounding down to the nearest bit
+    /// 
+    /// # Panics
+    /// if `rhs` is zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(120) / 10, MemorySize::from_bytes(12));
+    /// assert_eq!(MemorySize::from_bits(7) / 2, MemorySize::from_bits(3));
+    /// ```
+    fn div(self, rhs: u64) -> MemorySize {
+        MemorySize { size_bits: self.size_bits / rhs }
+    }
+}
+
+impl MulAssign<u64> for MemorySize {
+    /// Multiplies `&self` by `rhs` in place
+    /// 
+    /// # Panics
+    /// if the result overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let mut capacity = MemorySize::from_bytes(64);
+    /// capacity *= 2;
+    /// assert_eq!(capacity, MemorySize::from_bytes(128));
+    /// ```
+    fn mul_assign(&mut self, rhs: u64) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<u64> for MemorySize {
+    /// Divides `&self` by `rhs` in place, rounding down to the nearest bit
+    /// 
+    /// # Panics
+    /// if `rhs` is zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let mut capacity = MemorySize::from_bytes(128);
+    /// capacity /= 4;
+    /// assert_eq!(capacity, MemorySize::from_bytes(32));
+    /// ```
+    fn div_assign(&mut self, rhs: u64) {
+        *self = *self / rhs;
+    }
+}
+
+impl Div<MemorySize> for MemorySize {
+    type Output = u64;
+
+    /// Calculates how many whole times `rhs` fits into `&self`
+    /// 
+    /// # Panics
+    /// if `rhs` is zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let page = MemorySize::from_bytes(4096);
+    /// assert_eq!(MemorySize::from_bytes(10_000) / page, 2);
+    /// ```
+    fn div(self, rhs: MemorySize) -> u64 {
+        self.size_bits / rhs.size_bits
+    }
+}
+
+impl Rem<MemorySize> for MemorySize {
+    type Output = MemorySize;
+
+    /// Calculates the size left over after taking as many whole `rhs` out of `&self` as possible
+    /// 
+    /// # Panics
+    /// if `rhs` is zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let total = MemorySize::from_bytes(10_000);
+    /// let chunk = MemorySize::from_bytes(4096);
+    /// assert_eq!(chunk * (total / chunk) + total % chunk, total);
+    /// ```
+    fn rem(self, rhs: MemorySize) -> MemorySize {
+        MemorySize { size_bits: self.size_bits % rhs.size_bits }
+    }
+}
+
+impl Shl<u32> for MemorySize {
+    type Output = MemorySize;
+
+    /// Multiplies `&self` by `2^rhs`
+    /// 
+    /// # Panics
+    /// if the result overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(64) << 1, MemorySize::from_bytes(128));
+    /// ```
+    fn shl(self, rhs: u32) -> MemorySize {
+        self.checked_shl(rhs).unwrap()
+    }
+}
+
+impl Shr<u32> for MemorySize {
+    type Output = MemorySize;
+
+    /// Divides `&self` by `2^rhs`, rounding down to the nearest bit
+    /// 
+    /// # Panics
+    /// if `rhs` is 64 or more
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(64) >> 1, MemorySize::from_bytes(32));
+    /// ```
+    fn shr(self, rhs: u32) -> MemorySize {
+        self.checked_shr(rhs).unwrap()
+    }
+}
+
+/// Implements a binary operator for every combination of `MemorySize` and `&MemorySize`, using the implementation for values
+macro_rules! impl_ref_ops {
+    ($($t:ident, $method:ident, $assign_t:ident, $assign_method:ident);*) => {
+        $(
+            impl $t<&MemorySize> for MemorySize {
+                type Output = MemorySize;
+
+                fn $method(self, rhs: &MemorySize) -> MemorySize {
+                    $t::$method(self, *rhs)
+                }
+            }
+
+            impl $t<MemorySize> for &MemorySize {
+                type Output = MemorySize;
+
+                fn $method(self, rhs: MemorySize) -> MemorySize {
+                    $t::$method(*self, rhs)
+                }
+            }
+
+            impl $t<&MemorySize> for &MemorySize {
+                type Output = MemorySize;
+
+                fn $method(self, rhs: &MemorySize) -> MemorySize {
+                    $t::$method(*self, *rhs)
+                }
+            }
+
+            impl $assign_t<&MemorySize> for MemorySize {
+                fn $assign_method(&mut self, rhs: &MemorySize) {
+                    $assign_t::$assign_method(self, *rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl_ref_ops!(Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign);
+
+impl<'a> core::iter::Sum<&'a MemorySize> for MemorySize {
+    fn sum<I: Iterator<Item = &'a MemorySize>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
@@ -0,0 +1,207 @@
+//! Async versions of the adapters in `memory_size::io`, for use with tokio
+
+use crate::io::TokenBucket;
+use crate::{ByteRate, MemorySize};
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, ReadBuf, Take};
+use tokio::time::Sleep;
+
+/// Extends `AsyncRead` with size-limited adapters
+pub trait AsyncReadSizedExt: AsyncRead + Sized {
+    /// Creates an adapter that reads at most `limit` from this reader, like `AsyncReadExt::take`
+    /// 
+    /// Limits that are not a whole number of bytes are rounded down
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use memory_size::{async_io::AsyncReadSizedExt, MemorySize};
+    /// use tokio::io::AsyncReadExt;
+    ///
+    /// let data = [0u8; 100];
+    /// let mut body = Vec::new();
+    /// data.as_slice().take_sized(MemorySize::from_bytes(10)).read_to_end(&mut body).await.unwrap();
+    /// assert_eq!(body.len(), 10);
+    /// # });
+    /// ```
+    fn take_sized(self, limit: MemorySize) -> Take<Self> {
+        self.take(limit.size_bits_bytes().1)
+    }
+}
+
+impl<R: AsyncRead> AsyncReadSizedExt for R {}
+
+/// Wraps an async reader or writer, counting the amount of data transferred through it
+/// 
+/// # Examples
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use memory_size::{async_io::AsyncCounting, MemorySize};
+/// use tokio::io::AsyncWriteExt;
+///
+/// let mut output = AsyncCounting::new(Vec::new());
+/// output.write_all(b"hello").await.unwrap();
+/// assert_eq!(output.transferred(), MemorySize::from_bytes(5));
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct AsyncCounting<T> {
+    inner: T,
+    transferred: MemorySize,
+}
+
+impl<T> AsyncCounting<T> {
+    /// Wraps `inner`, starting the count at zero
+    pub fn new(inner: T) -> AsyncCounting<T> {
+        AsyncCounting { inner, transferred: MemorySize::new() }
+    }
+
+    /// Returns the amount of data read or written so far
+    pub fn transferred(&self) -> MemorySize {
+        self.transferred
+    }
+
+    /// Returns a reference to the wrapped reader or writer
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwraps the counter, returning the wrapped reader or writer
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for AsyncCounting<R> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let before = buf.filled().len();
+        ready!(Pin::new(&mut this.inner).poll_read(cx, buf))?;
+
+        this.transferred += MemorySize::from_bytes((buf.filled().len() - before) as u64);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for AsyncCounting<W> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let written = ready!(Pin::new(&mut this.inner).poll_write(cx, buf))?;
+
+        this.transferred += MemorySize::from_bytes(written as u64);
+        Poll::Ready(Ok(written))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+/// Wraps an async reader or writer, limiting its throughput to a `ByteRate`
+/// 
+/// Like `io::Throttle`, this uses a token bucket holding up to one second of transfer,
+/// but waits using a tokio timer instead of blocking the thread
+/// 
+/// # Examples
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+/// use memory_size::{async_io::AsyncThrottle, ByteRate, MemorySize};
+/// use tokio::io::AsyncWriteExt;
+///
+/// let rate = ByteRate::per_second(MemorySize::from_bytes(1024 * 1024));
+/// let mut output = AsyncThrottle::new(Vec::new(), rate);
+/// output.write_all(b"hello").await.unwrap();
+/// assert_eq!(output.into_inner(), b"hello");
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct AsyncThrottle<T> {
+    inner: T,
+    bucket: TokenBucket,
+    /// Timer for when the bucket will next allow data through
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl<T> AsyncThrottle<T> {
+    /// Creates a throttle that limits `inner` to `rate`
+    /// 
+    /// # Panics
+    /// if `rate` is less than one byte per second
+    pub fn new(inner: T, rate: ByteRate) -> AsyncThrottle<T> {
+        AsyncThrottle { inner, bucket: TokenBucket::new(rate), sleep: None }
+    }
+
+    /// Returns a reference to the wrapped reader or writer
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwraps the throttle, returning the wrapped reader or writer
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Waits until at least one byte can be transferred, then takes permission to transfer up to `wanted` bytes
+    fn poll_acquire(&mut self, cx: &mut Context<'_>, wanted: usize) -> Poll<usize> {
+        loop {
+            if let Some(sleep) = &mut self.sleep {
+                ready!(sleep.as_mut().poll(cx));
+                self.sleep = None;
+            }
+            match self.bucket.take(wanted) {
+                Ok(granted) => return Poll::Ready(granted),
+                Err(wait) => self.sleep = Some(Box::pin(tokio::time::sleep(wait))),
+            }
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for AsyncThrottle<R> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let granted = ready!(this.poll_acquire(cx, buf.remaining()));
+
+        let mut limited = ReadBuf::new(buf.initialize_unfilled_to(granted));
+        let result = Pin::new(&mut this.inner).poll_read(cx, &mut limited);
+        let read = match result {
+            Poll::Ready(Ok(())) => limited.filled().len(),
+            _ => 0,
+        };
+        buf.advance(read);
+        this.bucket.refund(granted - read);
+        result
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for AsyncThrottle<W> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let granted = ready!(this.poll_acquire(cx, buf.len()));
+
+        let result = Pin::new(&mut this.inner).poll_write(cx, &buf[..granted]);
+        let written = match result {
+            Poll::Ready(Ok(written)) => written,
+            _ => 0,
+        };
+        this.bucket.refund(granted - written);
+        result
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
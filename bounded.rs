@@ -0,0 +1,74 @@
+use crate::{MemorySize, MemorySizeError};
+
+/// A `MemorySize` that is known to be between `MIN_BYTES` and `MAX_BYTES` inclusive
+/// 
+/// This is intended for configuration structs: with the `serde` feature, deserializing checks the bounds
+/// and reports an error naming the violated bound
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{BoundedSize, MemorySize};
+///
+/// // a cache between 1 KiB and 1 MiB
+/// type CacheSize = BoundedSize<1024, { 1024 * 1024 }>;
+///
+/// let size = CacheSize::new(MemorySize::from_bytes(4096)).unwrap();
+/// assert_eq!(size.get(), MemorySize::from_bytes(4096));
+///
+/// assert!(CacheSize::new(MemorySize::from_bytes(10)).is_err());
+/// ```
+#[derive(PartialEq, PartialOrd, Ord, Eq, Clone, Copy, Hash, Debug)]
+pub struct BoundedSize<const MIN_BYTES: u64, const MAX_BYTES: u64>(MemorySize);
+
+impl<const MIN_BYTES: u64, const MAX_BYTES: u64> BoundedSize<MIN_BYTES, MAX_BYTES> {
+    /// The smallest allowed size
+    pub const MIN: MemorySize = MemorySize::from_bytes(MIN_BYTES);
+    /// The largest allowed size
+    pub const MAX: MemorySize = MemorySize::from_bytes(MAX_BYTES);
+
+    /// Checks that `size` is within the bounds
+    /// 
+    /// # Errors
+    /// `MemorySizeError::OutOfBounds` if `size` is smaller than `MIN_BYTES` or larger than `MAX_BYTES`
+    pub const fn new(size: MemorySize) -> Result<Self, MemorySizeError> {
+        if size.size_bits() < Self::MIN.size_bits() || size.size_bits() > Self::MAX.size_bits() {
+            return Err(MemorySizeError::OutOfBounds { size, min: Self::MIN, max: Self::MAX });
+        }
+        Ok(BoundedSize(size))
+    }
+
+    /// Returns the size
+    pub const fn get(&self) -> MemorySize {
+        self.0
+    }
+}
+
+impl<const MIN_BYTES: u64, const MAX_BYTES: u64> From<BoundedSize<MIN_BYTES, MAX_BYTES>> for MemorySize {
+    fn from(value: BoundedSize<MIN_BYTES, MAX_BYTES>) -> Self {
+        value.0
+    }
+}
+
+impl<const MIN_BYTES: u64, const MAX_BYTES: u64> TryFrom<MemorySize> for BoundedSize<MIN_BYTES, MAX_BYTES> {
+    type Error = MemorySizeError;
+
+    fn try_from(value: MemorySize) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const MIN_BYTES: u64, const MAX_BYTES: u64> serde::Serialize for BoundedSize<MIN_BYTES, MAX_BYTES> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+impl<'de, const MIN_BYTES: u64, const MAX_BYTES: u64> serde::Deserialize<'de> for BoundedSize<MIN_BYTES, MAX_BYTES> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let size = MemorySize::deserialize(deserializer)?;
+        Self::new(size).map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,585 @@
+//! Computing struct layouts from plain C declarations, for checking FFI definitions against another target
+
+use crate::{MemorySize, Primitive, SizeAlign, TargetLayout};
+
+/// Errors from parsing C struct declarations, recording the byte offset in the source where parsing failed
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+#[non_exhaustive]
+pub enum CStructError {
+    /// The source was not a struct declaration, or used C syntax that is not supported, like bitfields or unions
+    Syntax {
+        /// Offset of the unexpected token, or the length of the source if it ended early
+        offset: usize,
+    },
+    /// A field used a type that is not known, like an undeclared struct
+    UnknownType {
+        /// Offset of the start of the type
+        offset: usize,
+    },
+    /// A struct or array was too large to represent
+    Overflow {
+        /// Offset of the field that overflowed
+        offset: usize,
+    },
+}
+
+impl CStructError {
+    /// Returns the byte offset in the source where parsing failed
+    pub const fn offset(&self) -> usize {
+        match self {
+            CStructError::Syntax { offset }
+            | CStructError::UnknownType { offset }
+            | CStructError::Overflow { offset } => *offset,
+        }
+    }
+}
+
+impl std::fmt::Display for CStructError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CStructError::Syntax { offset } => write!(f, "unsupported or invalid C syntax at byte {}", offset),
+            CStructError::UnknownType { offset } => write!(f, "unknown type at byte {}", offset),
+            CStructError::Overflow { offset } => write!(f, "field at byte {} is too large to represent", offset),
+        }
+    }
+}
+
+impl std::error::Error for CStructError {}
+
+/// Errors from generating Rust code for a `StructLayout` whose names cannot be used in Rust
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+#[non_exhaustive]
+pub enum RustModuleError {
+    /// The struct is named `self`, `super`, `crate`, `Self` or `_`, which cannot be module names even as raw identifiers
+    InvalidName {
+        /// The name of the struct
+        name: String,
+    },
+    /// Two fields, like `x` and `X`, would generate the same constants
+    DuplicateConstant {
+        /// The name of the second field
+        name: String,
+    },
+}
+
+impl std::fmt::Display for RustModuleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RustModuleError::InvalidName { name } => write!(f, "`{}` cannot be used as a Rust module name", name),
+            RustModuleError::DuplicateConstant { name } => write!(f, "the constants for field `{}` are already defined by another field", name),
+        }
+    }
+}
+
+impl std::error::Error for RustModuleError {}
+
+//keywords that need to be written as raw identifiers to be used as names, in every edition
+const RUST_KEYWORDS: [&str; 48] = [
+    "as", "break", "const", "continue", "else", "enum", "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop",
+    "match", "mod", "move", "mut", "pub", "ref", "return", "static", "struct", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "async", "await", "dyn", "abstract", "become", "box", "do", "final", "macro", "override", "priv",
+    "typeof", "unsized", "virtual", "yield", "try", "gen",
+];
+
+/// A field of a `StructLayout`
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+pub struct StructField {
+    name: String,
+    offset: MemorySize,
+    layout: SizeAlign,
+    c_type: CType,
+    pointers: usize,
+    dimensions: Vec<u64>,
+}
+
+/// The type of a field as written in C, before any pointers or array dimensions
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+enum CType {
+    /// A named type, like `unsigned long`, `uint8_t` or `struct header`
+    Named(String),
+    /// An untagged struct declared inline, and its fields
+    Anonymous(Vec<StructField>, SizeAlign),
+}
+
+impl StructField {
+    /// Returns the name of the field
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the offset of the field from the start of the struct
+    pub const fn offset(&self) -> MemorySize {
+        self.offset
+    }
+
+    /// Returns the size and alignment of the field
+    pub const fn layout(&self) -> SizeAlign {
+        self.layout
+    }
+}
+
+/// The layout of a C struct on a target, created by `TargetLayout::parse_c_structs`
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+pub struct StructLayout {
+    name: String,
+    layout: SizeAlign,
+    fields: Vec<StructField>,
+}
+
+impl StructLayout {
+    /// Returns the tag of the struct, like `header` for `struct header`
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the size and alignment of the whole struct, including trailing padding
+    pub const fn layout(&self) -> SizeAlign {
+        self.layout
+    }
+
+    /// Returns the fields in declaration order
+    pub fn fields(&self) -> &[StructField] {
+        &self.fields
+    }
+
+    /// Finds the field called `name`
+    pub fn field(&self, name: &str) -> Option<&StructField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Generates a Rust module named after the struct, with the size and alignment of the struct
+    /// and the offset and size of each field as `usize` constants in bytes
+    /// 
+    /// This lets bindings index into raw buffers laid out for the target, like network packets or shared memory.
+    /// A struct named after a Rust keyword, like `type`, gets a raw identifier like `r#type` as its module name
+    /// 
+    /// # Errors
+    /// - `RustModuleError::InvalidName` if the struct is named `self`, `super`, `crate`, `Self` or `_`
+    /// - `RustModuleError::DuplicateConstant` if two field names only differ in case, so their constants would clash
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::TargetLayout;
+    ///
+    /// let layouts = TargetLayout::X86_64_SYSV.parse_c_structs("struct point { uint8_t tag; int32_t x; };").unwrap();
+    /// assert_eq!(layouts[0].rust_module().unwrap(), "\
+    /// /// Layout of `struct point`
+    /// pub mod point {
+    ///     pub const SIZE: usize = 8;
+    ///     pub const ALIGN: usize = 4;
+    ///     pub const TAG_OFFSET: usize = 0;
+    ///     pub const TAG_SIZE: usize = 1;
+    ///     pub const X_OFFSET: usize = 4;
+    ///     pub const X_SIZE: usize = 4;
+    /// }
+    /// ");
+    /// ```
+    pub fn rust_module(&self) -> Result<String, RustModuleError> {
+        use std::fmt::Write;
+
+        //C identifiers are always valid Rust identifiers, apart from keywords
+        let module = match self.name.as_str() {
+            "self" | "super" | "crate" | "Self" | "_" => return Err(RustModuleError::InvalidName { name: self.name.clone() }),
+            name if RUST_KEYWORDS.contains(&name) => format!("r#{}", name),
+            name => name.to_string(),
+        };
+
+        //structs from `parse_c_structs` are always whole bytes
+        let mut out = String::new();
+        writeln!(out, "/// Layout of `struct {}`", self.name).unwrap();
+        writeln!(out, "pub mod {} {{", module).unwrap();
+        writeln!(out, "    pub const SIZE: usize = {};", self.layout.size().size_bytes()).unwrap();
+        writeln!(out, "    pub const ALIGN: usize = {};", self.layout.align().size_bytes()).unwrap();
+        //the suffixes keep the constants from being keywords, or clashing with `SIZE` and `ALIGN`
+        let mut constants = std::collections::HashSet::new();
+        for field in &self.fields {
+            let name = field.name.to_ascii_uppercase();
+            if !constants.insert(name.clone()) {
+                return Err(RustModuleError::DuplicateConstant { name: field.name.clone() });
+            }
+            writeln!(out, "    pub const {}_OFFSET: usize = {};", name, field.offset.size_bytes()).unwrap();
+            writeln!(out, "    pub const {}_SIZE: usize = {};", name, field.layout.size().size_bytes()).unwrap();
+        }
+        out.push_str("}\n");
+        Ok(out)
+    }
+
+    /// Generates a C definition of the struct with every padding byte written out as a `uint8_t _padN[]` field,
+    /// followed by `_Static_assert`s of its `sizeof` and `_Alignof`
+    /// 
+    /// Compiling the output with a C compiler for the target checks that it lays the struct out in the same way.
+    /// Fields keep the types they were declared with, so the output expects `<stdint.h>`, and `<stddef.h>` or `<stdbool.h>` if those types are used,
+    /// and any structs it refers to to be defined first, as `parse_c_structs` returns them
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::TargetLayout;
+    ///
+    /// let layouts = TargetLayout::X86_64_SYSV.parse_c_structs("struct point { uint8_t tag; int32_t x; };").unwrap();
+    /// assert_eq!(layouts[0].c_definition(), "\
+    /// struct point {
+    ///     uint8_t tag;
+    ///     uint8_t _pad0[3];
+    ///     int32_t x;
+    /// };
+    /// _Static_assert(sizeof(struct point) == 8, \"size of struct point\");
+    /// _Static_assert(_Alignof(struct point) == 4, \"alignment of struct point\");
+    /// ");
+    /// ```
+    pub fn c_definition(&self) -> String {
+        let mut out = format!("struct {} {{\n", self.name);
+        write_c_fields(&mut out, &self.fields, self.layout.size(), 1, &mut 0);
+        out.push_str("};\n");
+        out.push_str(&format!("_Static_assert(sizeof(struct {0}) == {1}, \"size of struct {0}\");\n", self.name, self.layout.size().size_bytes()));
+        out.push_str(&format!("_Static_assert(_Alignof(struct {0}) == {1}, \"alignment of struct {0}\");\n", self.name, self.layout.align().size_bytes()));
+        out
+    }
+}
+
+/// Writes C declarations of `fields` at `depth` levels of indentation, with padding fields up to each offset and up to `size`
+/// 
+/// `padding` counts the padding fields written so far, so that their names are unique
+fn write_c_fields(out: &mut String, fields: &[StructField], size: MemorySize, depth: usize, padding: &mut usize) {
+    let indent = "    ".repeat(depth);
+    let mut end = MemorySize::new();
+    for field in fields {
+        write_c_padding(out, &indent, padding, end, field.offset);
+        out.push_str(&indent);
+        match &field.c_type {
+            CType::Named(name) => out.push_str(name),
+            CType::Anonymous(inner, layout) => {
+                out.push_str("struct {\n");
+                write_c_fields(out, inner, layout.size(), depth + 1, padding);
+                out.push_str(&indent);
+                out.push('}');
+            }
+        }
+        out.push(' ');
+        out.push_str(&"*".repeat(field.pointers));
+        out.push_str(&field.name);
+        for dimension in &field.dimensions {
+            out.push_str(&format!("[{}]", dimension));
+        }
+        out.push_str(";\n");
+        end = field.offset + field.layout.size();
+    }
+    write_c_padding(out, &indent, padding, end, size);
+}
+
+/// Writes a padding field covering `from..to`, if it is not empty
+fn write_c_padding(out: &mut String, indent: &str, padding: &mut usize, from: MemorySize, to: MemorySize) {
+    if to > from {
+        out.push_str(&format!("{}uint8_t _pad{}[{}];\n", indent, padding, (to - from).size_bytes()));
+        *padding += 1;
+    }
+}
+
+/// A token of C source, and its byte offset
+#[derive(Clone, Copy)]
+struct Token<'a> {
+    kind: TokenKind<'a>,
+    offset: usize,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum TokenKind<'a> {
+    Ident(&'a str),
+    Number(u64),
+    Punct(char),
+}
+
+/// Splits `source` into tokens, skipping whitespace, comments and preprocessor lines
+fn tokenize(source: &str) -> Result<Vec<Token<'_>>, CStructError> {
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let mut line_start = true;
+    while let Some(c) = source[i..].chars().next() {
+        let rest = &source[i..];
+        if c == '\n' {
+            line_start = true;
+            i += 1;
+            continue;
+        }
+        if c.is_whitespace() {
+            i += c.len_utf8();
+            continue;
+        }
+        if rest.starts_with("//") || (line_start && c == '#') {
+            i += rest.find('\n').unwrap_or(rest.len());
+            continue;
+        }
+        line_start = false;
+        if rest.starts_with("/*") {
+            i += rest.find("*/").map(|end| end + 2).ok_or(CStructError::Syntax { offset: i })?;
+            continue;
+        }
+
+        let len = if c.is_ascii_alphabetic() || c == '_' {
+            let len = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(rest.len());
+            tokens.push(Token { kind: TokenKind::Ident(&rest[..len]), offset: i });
+            len
+        } else if c.is_ascii_digit() {
+            let len = rest.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(rest.len());
+            //C integer suffixes, like `16u`, do not change the value
+            let digits = rest[..len].trim_end_matches(['u', 'U', 'l', 'L']);
+            let parsed = match digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+                Some(hex) => u64::from_str_radix(hex, 16),
+                None => digits.parse(),
+            };
+            let number = parsed.map_err(|err| match err.kind() {
+                std::num::IntErrorKind::PosOverflow => CStructError::Overflow { offset: i },
+                _ => CStructError::Syntax { offset: i },
+            })?;
+            tokens.push(Token { kind: TokenKind::Number(number), offset: i });
+            len
+        } else if "{};*[],".contains(c) {
+            tokens.push(Token { kind: TokenKind::Punct(c), offset: i });
+            1
+        } else {
+            return Err(CStructError::Syntax { offset: i });
+        };
+        i += len;
+    }
+    Ok(tokens)
+}
+
+/// The type at the start of a member declaration, and how it was written
+#[derive(Clone)]
+enum BaseType {
+    Known(SizeAlign, CType),
+    /// A type that is only valid behind a pointer, like `void` or an undeclared struct, and its offset
+    Incomplete(usize, CType),
+}
+
+/// Reads struct declarations from a list of tokens
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    position: usize,
+    end: usize,
+    target: TargetLayout,
+    structs: Vec<StructLayout>,
+}
+
+impl<'a> Parser<'a> {
+    /// Returns the next token without consuming it
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.position).copied()
+    }
+
+    /// Returns the offset of the next token, or the end of the source
+    fn offset(&self) -> usize {
+        self.peek().map_or(self.end, |token| token.offset)
+    }
+
+    /// Consumes the next token if it is `kind`
+    fn eat(&mut self, kind: TokenKind<'a>) -> bool {
+        let matched = self.peek().is_some_and(|token| token.kind == kind);
+        self.position += matched as usize;
+        matched
+    }
+
+    /// Consumes the next token, which must be `kind`
+    fn expect(&mut self, kind: TokenKind<'a>) -> Result<(), CStructError> {
+        if self.eat(kind) {Ok(())} else {Err(CStructError::Syntax { offset: self.offset() })}
+    }
+
+    /// Consumes an identifier
+    fn ident(&mut self) -> Result<&'a str, CStructError> {
+        match self.peek() {
+            Some(Token { kind: TokenKind::Ident(name), .. }) => {
+                self.position += 1;
+                Ok(name)
+            }
+            _ => Err(CStructError::Syntax { offset: self.offset() }),
+        }
+    }
+
+    /// Parses `struct`, an optional tag, and either a body or a reference to a struct declared earlier
+    fn parse_struct(&mut self) -> Result<BaseType, CStructError> {
+        let start = self.offset();
+        self.expect(TokenKind::Ident("struct"))?;
+        let tag = if let Some(Token { kind: TokenKind::Ident(tag), .. }) = self.peek() {
+            self.position += 1;
+            Some(tag)
+        } else {
+            None
+        };
+
+        if !self.eat(TokenKind::Punct('{')) {
+            let tag = tag.ok_or(CStructError::Syntax { offset: self.offset() })?;
+            let c_type = CType::Named(format!("struct {}", tag));
+            return Ok(match self.structs.iter().rev().find(|layout| layout.name == tag) {
+                Some(layout) => BaseType::Known(layout.layout, c_type),
+                None => BaseType::Incomplete(start, c_type),
+            });
+        }
+
+        let mut layout = SizeAlign::new(MemorySize::new(), MemorySize::from_bytes(1));
+        let mut fields = Vec::new();
+        while !self.eat(TokenKind::Punct('}')) {
+            self.parse_member(&mut layout, &mut fields)?;
+        }
+        let size = layout.size().checked_align_up(layout.align()).ok_or(CStructError::Overflow { offset: start })?;
+        let layout = SizeAlign::new(size, layout.align());
+        match tag {
+            Some(tag) => {
+                self.structs.push(StructLayout { name: tag.to_string(), layout, fields });
+                Ok(BaseType::Known(layout, CType::Named(format!("struct {}", tag))))
+            }
+            None => Ok(BaseType::Known(layout, CType::Anonymous(fields, layout))),
+        }
+    }
+
+    /// Parses the type specifiers of a member, like `unsigned long` or `uint32_t`, leaving the declarator
+    fn parse_base_type(&mut self) -> Result<BaseType, CStructError> {
+        let start = self.offset();
+        let mut qualifiers = Vec::new();
+        while let Some(Token { kind: TokenKind::Ident(qualifier @ ("const" | "volatile")), .. }) = self.peek() {
+            qualifiers.push(qualifier);
+            self.position += 1;
+        }
+        if self.peek().is_some_and(|token| token.kind == TokenKind::Ident("struct")) {
+            //qualifiers do not change the layout, so they are only kept in the name of the type
+            let qualify = |c_type| match c_type {
+                CType::Named(name) if !qualifiers.is_empty() => CType::Named(format!("{} {}", qualifiers.join(" "), name)),
+                c_type => c_type,
+            };
+            return Ok(match self.parse_struct()? {
+                BaseType::Known(layout, c_type) => BaseType::Known(layout, qualify(c_type)),
+                BaseType::Incomplete(offset, c_type) => BaseType::Incomplete(offset, qualify(c_type)),
+            });
+        }
+        self.position -= qualifiers.len();
+
+        let mut words = Vec::new();
+        while let Some(Token { kind: TokenKind::Ident(word), .. }) = self.peek() {
+            words.push(word);
+            self.position += 1;
+        }
+        //without a pointer, the last identifier is the field name
+        if !self.peek().is_some_and(|token| token.kind == TokenKind::Punct('*')) && !words.is_empty() {
+            words.pop();
+            self.position -= 1;
+        }
+        let c_type = CType::Named(words.join(" "));
+        //`signed` and `unsigned` on their own mean `int`, but a member with no type words at all is an error
+        let has_sign = words.iter().any(|word| matches!(*word, "signed" | "unsigned"));
+        words.retain(|word| !matches!(*word, "const" | "volatile" | "signed" | "unsigned"));
+
+        let primitive = match words.as_slice() {
+            ["void"] => return Ok(BaseType::Incomplete(start, c_type)),
+            ["char" | "_Bool" | "bool" | "int8_t" | "uint8_t"] => Primitive::I8,
+            ["short"] | ["short", "int"] | ["int16_t" | "uint16_t"] => Primitive::I16,
+            [] if has_sign => Primitive::I32,
+            ["int"] | ["int32_t" | "uint32_t"] => Primitive::I32,
+            ["long", "long"] | ["long", "long", "int"] | ["int64_t" | "uint64_t"] => Primitive::I64,
+            ["float"] => Primitive::F32,
+            ["double"] => Primitive::F64,
+            ["long"] | ["long", "int"] | ["size_t" | "ssize_t" | "ptrdiff_t" | "intptr_t" | "uintptr_t"] => Primitive::Pointer,
+            _ => return Err(CStructError::UnknownType { offset: start }),
+        };
+        Ok(BaseType::Known(self.target.primitive(primitive), c_type))
+    }
+
+    /// Parses a member declaration, like `uint8_t tag, *next, data[4];`, adding its fields to `layout` and `fields`
+    fn parse_member(&mut self, layout: &mut SizeAlign, fields: &mut Vec<StructField>) -> Result<(), CStructError> {
+        let base = self.parse_base_type()?;
+        loop {
+            let offset = self.offset();
+            let mut pointers = 0;
+            while self.eat(TokenKind::Punct('*')) {
+                pointers += 1;
+            }
+            let (mut field, c_type) = match &base {
+                BaseType::Known(_, c_type) | BaseType::Incomplete(_, c_type) if pointers != 0 => (self.target.primitive(Primitive::Pointer), c_type.clone()),
+                BaseType::Known(field, c_type) => (*field, c_type.clone()),
+                //only pointers can refer to `void` or an undeclared struct
+                BaseType::Incomplete(type_offset, _) => return Err(CStructError::UnknownType { offset: *type_offset }),
+            };
+            let name = self.ident()?;
+
+            //array dimensions, applied from the innermost, which is written last
+            let mut dimensions = Vec::new();
+            while self.eat(TokenKind::Punct('[')) {
+                match self.peek() {
+                    Some(Token { kind: TokenKind::Number(n), .. }) => dimensions.push(n),
+                    _ => return Err(CStructError::Syntax { offset: self.offset() }),
+                }
+                self.position += 1;
+                self.expect(TokenKind::Punct(']'))?;
+            }
+            //every field type is already padded to its alignment, so the size is the stride
+            for &n in dimensions.iter().rev() {
+                let size = field.size().checked_mul(n).ok_or(CStructError::Overflow { offset })?;
+                field = SizeAlign::new(size, field.align());
+            }
+
+            //check for overflow first, as `extend` panics
+            let field_offset = layout.size().checked_align_up(field.align()).ok_or(CStructError::Overflow { offset })?;
+            field_offset.checked_add(field.size()).ok_or(CStructError::Overflow { offset })?;
+            let (extended, field_offset) = layout.extend(field);
+            *layout = extended;
+            fields.push(StructField { name: name.to_string(), offset: field_offset, layout: field, c_type, pointers, dimensions });
+
+            if !self.eat(TokenKind::Punct(',')) {
+                return self.expect(TokenKind::Punct(';'));
+            }
+        }
+    }
+}
+
+impl TargetLayout {
+    /// Computes the layouts of plain C struct declarations, like those in an FFI header, as this target would lay them out
+    ///
+    /// `source` is a sequence of declarations like `struct point { int32_t x, y; };`.
+    /// Fields can use the fixed-width integer types, `char`, `short`, `int`, `long` and `long long` with any signedness,
+    /// `float`, `double`, `bool`, `size_t` and the pointer-sized integer types, pointers, arrays, and structs,
+    /// either declared earlier by tag or inline. `long` is pointer-sized, as it is on every preset target.
+    /// Comments and preprocessor lines are skipped, but bitfields, unions, enums and typedefs are not supported
+    ///
+    /// Returns every tagged struct in the order its declaration is completed, so inline nested structs come before the structs containing them
+    ///
+    /// # Errors
+    /// a `CStructError` giving the reason and the byte offset of the problem
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, TargetLayout};
+    ///
+    /// let source = "
+    ///     struct node {
+    ///         uint8_t tag;
+    ///         struct node *next;
+    ///         uint16_t data[3];
+    ///     };
+    /// ";
+    /// let x86 = &TargetLayout::X86_64_SYSV.parse_c_structs(source).unwrap()[0];
+    /// assert_eq!(x86.field("data").unwrap().offset(), MemorySize::from_bytes(16));
+    /// assert_eq!(x86.layout().size(), MemorySize::from_bytes(24));
+    ///
+    /// let wasm = &TargetLayout::WASM32.parse_c_structs(source).unwrap()[0];
+    /// assert_eq!(wasm.field("data").unwrap().offset(), MemorySize::from_bytes(8));
+    /// assert_eq!(wasm.layout().size(), MemorySize::from_bytes(16));
+    /// ```
+    pub fn parse_c_structs(&self, source: &str) -> Result<Vec<StructLayout>, CStructError> {
+        let mut parser = Parser {
+            tokens: tokenize(source)?,
+            position: 0,
+            end: source.len(),
+            target: *self,
+            structs: Vec::new(),
+        };
+        while parser.peek().is_some() {
+            let offset = parser.offset();
+            match parser.parse_struct()? {
+                //a declaration must have a body
+                BaseType::Incomplete(..) => return Err(CStructError::Syntax { offset }),
+                BaseType::Known(..) => parser.expect(TokenKind::Punct(';'))?,
+            }
+        }
+        Ok(parser.structs)
+    }
+}
@@ -0,0 +1,116 @@
+use crate::MemorySize;
+
+/// The ratio between the size of some data before and after compression
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct CompressionRatio {
+    original: MemorySize,
+    compressed: MemorySize,
+}
+
+impl CompressionRatio {
+    /// Creates a `CompressionRatio` from the size of some data before and after compression
+    /// 
+    /// # Panics
+    /// if `original` is zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{CompressionRatio, MemorySize};
+    ///
+    /// let ratio = CompressionRatio::new(MemorySize::from_bytes(1000), MemorySize::from_bytes(250));
+    /// assert_eq!(ratio.ratio(), 4.0);
+    /// ```
+    pub const fn new(original: MemorySize, compressed: MemorySize) -> CompressionRatio {
+        assert!(original.size_bits() != 0, "original size must be non-zero");
+        CompressionRatio { original, compressed }
+    }
+
+    /// Returns the size before compression
+    pub const fn original(&self) -> MemorySize {
+        self.original
+    }
+
+    /// Returns the size after compression
+    pub const fn compressed(&self) -> MemorySize {
+        self.compressed
+    }
+
+    /// Calculates how many times smaller the compressed data is, i.e `original / compressed`
+    /// 
+    /// Returns `f64::INFINITY` if the compressed size is zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{CompressionRatio, MemorySize};
+    ///
+    /// let ratio = CompressionRatio::new(MemorySize::from_bytes(1000), MemorySize::new());
+    /// assert_eq!(ratio.ratio(), f64::INFINITY);
+    /// ```
+    pub fn ratio(&self) -> f64 {
+        self.original.size_bits() as f64 / self.compressed.size_bits() as f64
+    }
+
+    /// Calculates the fraction of the original size that was saved by compressing
+    /// 
+    /// This is negative if compressing made the data larger
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{CompressionRatio, MemorySize};
+    ///
+    /// let ratio = CompressionRatio::new(MemorySize::from_bytes(1000), MemorySize::from_bytes(250));
+    /// assert_eq!(ratio.space_saving(), 0.75);
+    /// ```
+    pub fn space_saving(&self) -> f64 {
+        1.0 - self.compressed.size_bits() as f64 / self.original.size_bits() as f64
+    }
+
+    /// Estimates the compressed size of `size`, assuming it compresses as well as the data this ratio was measured from
+    /// 
+    /// The estimate is rounded up to the next bit
+    /// 
+    /// # Panics
+    /// if the estimate overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{CompressionRatio, MemorySize};
+    ///
+    /// let ratio = CompressionRatio::new(MemorySize::from_bytes(1000), MemorySize::from_bytes(250));
+    /// assert_eq!(ratio.apply(MemorySize::from_bytes(40)), MemorySize::from_bytes(10));
+    /// ```
+    pub const fn apply(&self, size: MemorySize) -> MemorySize {
+        let scaled = (size.size_bits() as u128 * self.compressed.size_bits() as u128)
+            .div_ceil(self.original.size_bits() as u128);
+        assert!(scaled <= u64::MAX as u128, "estimated size overflows");
+
+        MemorySize::from_bits(scaled as u64)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for CompressionRatio {
+    /// Formats the ratio and the space saved
+    ///
+    /// A zero compressed size has an infinite ratio, which is formatted as `∞:1`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{CompressionRatio, MemorySize};
+    ///
+    /// let ratio = CompressionRatio::new(MemorySize::from_bytes(3200), MemorySize::from_bytes(1000));
+    /// assert_eq!(ratio.to_string(), "3.2:1, saved 69%");
+    /// assert_eq!(CompressionRatio::new(MemorySize::from_bytes(10), MemorySize::new()).to_string(), "∞:1, saved 100%");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.compressed.size_bits() == 0 {
+            return write!(f, "∞:1, saved 100%");
+        }
+        write!(f, "{:.1}:1, saved {:.0}%", self.ratio(), self.space_saving() * 100.0)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{MemorySize, BITS_IN_BYTE};
+use core::fmt;
+
+/// A `MemorySize` broken down into whole binary units, created by `MemorySize::decompose`
+///
+/// Each field is less than 1024 of its unit, except `bits` which is less than 8
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug, Default)]
+pub struct Decomposed {
+    /// Exbibytes
+    pub eib: u64,
+    /// Pebibytes
+    pub pib: u64,
+    /// Tebibytes
+    pub tib: u64,
+    /// Gibibytes
+    pub gib: u64,
+    /// Mebibytes
+    pub mib: u64,
+    /// Kibibytes
+    pub kib: u64,
+    /// Bytes
+    pub bytes: u64,
+    /// Bits left over after the last whole byte
+    pub bits: u64,
+}
+
+impl MemorySize {
+    /// Breaks `&self` down into the number of each binary unit, from exbibytes down to the bits left over from the last byte
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let size = MemorySize::from_bytes((3 << 29) + 12);
+    /// let parts = size.decompose();
+    /// assert_eq!((parts.gib, parts.mib, parts.kib, parts.bytes, parts.bits), (1, 512, 0, 12, 0));
+    /// assert_eq!(parts.to_string(), "1 GiB 512 MiB 12 B");
+    /// ```
+    pub const fn decompose(&self) -> Decomposed {
+        let bytes = self.size_bits / BITS_IN_BYTE;
+        Decomposed {
+            eib: bytes >> 60,
+            pib: (bytes >> 50) % 1024,
+            tib: (bytes >> 40) % 1024,
+            gib: (bytes >> 30) % 1024,
+            mib: (bytes >> 20) % 1024,
+            kib: (bytes >> 10) % 1024,
+            bytes: bytes % 1024,
+            bits: self.size_bits % BITS_IN_BYTE,
+        }
+    }
+}
+
+impl fmt::Display for Decomposed {
+    /// Formats the units that are not zero from largest to smallest, like `1 GiB 512 MiB 12 B`
+    ///
+    /// A size of zero is formatted as `0 B`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = [
+            (self.eib, "EiB"),
+            (self.pib, "PiB"),
+            (self.tib, "TiB"),
+            (self.gib, "GiB"),
+            (self.mib, "MiB"),
+            (self.kib, "KiB"),
+            (self.bytes, "B"),
+            (self.bits, "bit"),
+        ];
+
+        let mut first = true;
+        for (value, unit) in parts.into_iter().filter(|(value, _)| *value != 0) {
+            if !first {
+                f.write_str(" ")?;
+            }
+            write!(f, "{} {}", value, unit)?;
+            first = false;
+        }
+        if first {
+            f.write_str("0 B")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use crate::MemorySize;
+
+/// Text encodings of binary data, used to calculate encoded and decoded sizes
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum Encoding {
+    /// Base64 with `=` padding, as in RFC 4648
+    Base64,
+    /// Base64 without padding
+    Base64Unpadded,
+    /// Two hex digits per byte
+    Hex,
+    /// URL percent-encoding, where each byte is either kept as is or written as `%XX`
+    Percent,
+}
+
+impl Encoding {
+    /// Calculates the size of `input` once encoded
+    /// 
+    /// This is exact for every encoding except `Percent`, where the worst case of every byte being escaped is returned
+    /// 
+    /// # Panics
+    /// if `input` is not a whole number of bytes, or the encoded size overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{Encoding, MemorySize};
+    ///
+    /// let input = MemorySize::from_bytes(10);
+    /// assert_eq!(Encoding::Base64.encoded_size(input), MemorySize::from_bytes(16));
+    /// assert_eq!(Encoding::Base64Unpadded.encoded_size(input), MemorySize::from_bytes(14));
+    /// assert_eq!(Encoding::Hex.encoded_size(input), MemorySize::from_bytes(20));
+    /// assert_eq!(Encoding::Percent.encoded_size(input), MemorySize::from_bytes(30));
+    /// ```
+    pub const fn encoded_size(&self, input: MemorySize) -> MemorySize {
+        let bytes = input.size_bytes();
+        let encoded = match self {
+            Encoding::Base64 => bytes.div_ceil(3).checked_mul(4),
+            //every 3 bytes become 4 characters, and a partial group of n bytes becomes n+1 characters
+            Encoding::Base64Unpadded => match (bytes / 3).checked_mul(4) {
+                Some(full) => full.checked_add(match bytes % 3 {0 => 0, rem => rem + 1}),
+                None => None,
+            },
+            Encoding::Hex => bytes.checked_mul(2),
+            Encoding::Percent => bytes.checked_mul(3),
+        };
+        MemorySize::from_bytes(encoded.unwrap())
+    }
+
+    /// Calculates the largest size that `encoded` can decode to
+    /// 
+    /// This is exact for `Base64Unpadded` and `Hex`. Padded base64 may decode to up to two bytes less, depending on the padding,
+    /// and percent-encoded data may decode to as little as a third of the size, depending on how many bytes were escaped
+    /// 
+    /// Trailing characters that cannot form a whole byte are ignored
+    /// 
+    /// # Panics
+    /// if `encoded` is not a whole number of bytes
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{Encoding, MemorySize};
+    ///
+    /// assert_eq!(Encoding::Base64.decoded_size(MemorySize::from_bytes(16)), MemorySize::from_bytes(12));
+    /// assert_eq!(Encoding::Base64Unpadded.decoded_size(MemorySize::from_bytes(14)), MemorySize::from_bytes(10));
+    /// assert_eq!(Encoding::Hex.decoded_size(MemorySize::from_bytes(20)), MemorySize::from_bytes(10));
+    /// ```
+    pub const fn decoded_size(&self, encoded: MemorySize) -> MemorySize {
+        let chars = encoded.size_bytes();
+        let decoded = match self {
+            Encoding::Base64 => chars / 4 * 3,
+            //a partial group of n characters holds n-1 bytes, and a single leftover character holds none
+            Encoding::Base64Unpadded => chars / 4 * 3 + (chars % 4).saturating_sub(1),
+            Encoding::Hex => chars / 2,
+            Encoding::Percent => chars,
+        };
+        MemorySize::from_bytes(decoded)
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{MemorySize, MemorySizeParseError};
+
+/// Errors from reading a `MemorySize` from an environment variable
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[non_exhaustive]
+pub enum FromEnvError {
+    /// The variable was not set
+    NotPresent {
+        /// Name of the variable
+        var: String,
+    },
+    /// The variable was set, but was not valid unicode
+    NotUnicode {
+        /// Name of the variable
+        var: String,
+    },
+    /// The variable was set, but could not be parsed as a size
+    Invalid {
+        /// Name of the variable
+        var: String,
+        /// The value of the variable
+        value: String,
+        /// Why parsing failed
+        error: MemorySizeParseError,
+    },
+}
+
+impl std::fmt::Display for FromEnvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FromEnvError::NotPresent { var } => write!(f, "environment variable {} is not set", var),
+            FromEnvError::NotUnicode { var } => write!(f, "environment variable {} is not valid unicode", var),
+            FromEnvError::Invalid { var, value, error } => write!(f, "environment variable {} has the invalid size {:?}: {}", var, value, error),
+        }
+    }
+}
+
+impl std::error::Error for FromEnvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FromEnvError::Invalid { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl MemorySize {
+    /// Reads the environment variable `var` and parses it with `from_str`
+    /// 
+    /// # Errors
+    /// `FromEnvError::NotPresent` if `var` is not set, `FromEnvError::NotUnicode` if it is not valid unicode,
+    /// or `FromEnvError::Invalid` if it is not a valid size
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{FromEnvError, MemorySize};
+    ///
+    /// std::env::set_var("MY_APP_CACHE_SIZE", "256 MiB");
+    /// assert_eq!(MemorySize::from_env("MY_APP_CACHE_SIZE"), Ok(MemorySize::from_bytes(256 << 20)));
+    ///
+    /// std::env::remove_var("MY_APP_CACHE_SIZE");
+    /// assert!(matches!(MemorySize::from_env("MY_APP_CACHE_SIZE"), Err(FromEnvError::NotPresent { .. })));
+    /// ```
+    pub fn from_env(var: &str) -> Result<MemorySize, FromEnvError> {
+        let value = std::env::var(var).map_err(|e| match e {
+            std::env::VarError::NotPresent => FromEnvError::NotPresent { var: var.to_string() },
+            std::env::VarError::NotUnicode(_) => FromEnvError::NotUnicode { var: var.to_string() },
+        })?;
+
+        value.parse().map_err(|error| FromEnvError::Invalid { var: var.to_string(), value, error })
+    }
+
+    /// Reads the environment variable `var` and parses it with `from_str`, or returns `default` if it is not set
+    /// 
+    /// # Errors
+    /// the same as `from_env`, except that an unset variable is not an error
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let default = MemorySize::from_bytes(64 << 20);
+    /// std::env::remove_var("MY_APP_BUFFER_SIZE");
+    /// assert_eq!(MemorySize::from_env_or("MY_APP_BUFFER_SIZE", default), Ok(default));
+    /// ```
+    pub fn from_env_or(var: &str, default: MemorySize) -> Result<MemorySize, FromEnvError> {
+        match MemorySize::from_env(var) {
+            Err(FromEnvError::NotPresent { .. }) => Ok(default),
+            result => result,
+        }
+    }
+}
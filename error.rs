@@ -0,0 +1,80 @@
+use crate::{MemorySize, MemorySizeParseError};
+
+/// Errors produced by the fallible operations in this crate
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[non_exhaustive]
+pub enum MemorySizeError {
+    /// The allocator could not reserve the requested memory
+    #[cfg(feature = "std")]
+    Reserve(std::collections::TryReserveError),
+    /// A size was outside of the allowed range
+    OutOfBounds {
+        /// The size that was checked
+        size: MemorySize,
+        /// Smallest allowed size
+        min: MemorySize,
+        /// Largest allowed size
+        max: MemorySize,
+    },
+    /// A size was not a multiple of the required alignment
+    Unaligned {
+        /// The size that was checked
+        size: MemorySize,
+        /// The required alignment
+        alignment: MemorySize,
+    },
+    /// Text could not be parsed as a size
+    Parse(MemorySizeParseError),
+    /// A calculation produced a size too large to represent
+    Overflow,
+    /// A negative difference was converted into a size, or a calculation produced a negative size
+    Negative,
+    /// A size was multiplied by, or constructed from, a NaN or infinite value
+    InvalidFactor,
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for MemorySizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemorySizeError::Reserve(e) => write!(f, "failed to reserve memory: {}", e),
+            MemorySizeError::OutOfBounds { size, min, .. } if size < min => {
+                write!(f, "size {} is below the minimum of {}", size.display_round_trip(), min.display_round_trip())
+            }
+            MemorySizeError::OutOfBounds { size, max, .. } => {
+                write!(f, "size {} is above the maximum of {}", size.display_round_trip(), max.display_round_trip())
+            }
+            MemorySizeError::Unaligned { size, alignment } => {
+                write!(f, "size {} is not a multiple of {}", size.display_round_trip(), alignment.display_round_trip())
+            }
+            MemorySizeError::Parse(e) => write!(f, "failed to parse size: {}", e),
+            MemorySizeError::Overflow => write!(f, "size overflowed"),
+            MemorySizeError::Negative => write!(f, "size is negative"),
+            MemorySizeError::InvalidFactor => write!(f, "value is not a finite number"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MemorySizeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MemorySizeError::Reserve(e) => Some(e),
+            MemorySizeError::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::collections::TryReserveError> for MemorySizeError {
+    fn from(value: std::collections::TryReserveError) -> Self {
+        MemorySizeError::Reserve(value)
+    }
+}
+
+impl From<MemorySizeParseError> for MemorySizeError {
+    fn from(value: MemorySizeParseError) -> Self {
+        MemorySizeError::Parse(value)
+    }
+}
@@ -0,0 +1,173 @@
+//! Estimators for the heap memory used by standard library collections
+//! 
+//! These model the allocation strategy of the current standard library implementations,
+//! so they are estimates rather than guarantees
+
+use crate::{fallible, MemorySize};
+use core::mem::{align_of, size_of};
+
+/// Width of a hashbrown control group, using the SSE2 implementation
+const HASH_GROUP_WIDTH: u64 = 16;
+
+/// Calculates the heap memory used by a `Vec<T>` with the specified capacity
+/// 
+/// # Panics
+/// if the size would overflow `u64` bytes
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{MemorySize, estimate};
+///
+/// assert_eq!(estimate::vec_heap_size::<u32>(100), MemorySize::from_bytes(400));
+/// assert_eq!(estimate::vec_heap_size::<()>(100), MemorySize::new());
+/// ```
+pub const fn vec_heap_size<T>(capacity: usize) -> MemorySize {
+    MemorySize::from_bytes((capacity as u64).checked_mul(size_of::<T>() as u64).unwrap())
+}
+
+/// Calculates the heap memory used by a `String` with the specified capacity
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{MemorySize, estimate};
+///
+/// assert_eq!(estimate::string_heap_size(64), MemorySize::from_bytes(64));
+/// ```
+pub const fn string_heap_size(capacity: usize) -> MemorySize {
+    vec_heap_size::<u8>(capacity)
+}
+
+/// Calculates the number of buckets allocated by a `HashMap` that must hold `capacity` entries
+/// 
+/// The table is kept at most 7/8 full, and always has a power of two number of buckets
+const fn hash_map_buckets(capacity: u64) -> u64 {
+    if capacity == 0 {
+        0
+    } else if capacity < 4 {
+        4
+    } else if capacity < 8 {
+        8
+    } else {
+        (capacity.checked_mul(8).unwrap() / 7).next_power_of_two()
+    }
+}
+
+/// Calculates the heap memory used by a `HashMap<K, V>` with the specified capacity
+/// 
+/// This includes the empty buckets left by the 7/8 maximum load factor, and the control byte stored for each bucket
+/// 
+/// # Panics
+/// if the size would overflow `u64` bytes
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{MemorySize, estimate};
+///
+/// // 1000 entries need 2048 buckets of 16 bytes each, plus 2048 + 16 control bytes
+/// let size = estimate::hash_map_heap_size::<u64, u64>(1000);
+/// assert_eq!(size, MemorySize::from_bytes(2048 * 16 + 2048 + 16));
+///
+/// assert_eq!(estimate::hash_map_heap_size::<u64, u64>(0), MemorySize::new());
+/// ```
+pub const fn hash_map_heap_size<K, V>(capacity: usize) -> MemorySize {
+    hash_map_table_size::<K, V>(hash_map_buckets(capacity as u64)).unwrap()
+}
+
+/// Calculates the heap memory used by a hash table with `buckets` buckets
+/// 
+/// Returns `None` if the size would overflow
+const fn hash_map_table_size<K, V>(buckets: u64) -> Option<MemorySize> {
+    if buckets == 0 {
+        return Some(MemorySize::new());//empty maps do not allocate
+    }
+
+    let entry_size = size_of::<(K, V)>() as u64;
+    let ctrl_align = if align_of::<(K, V)>() as u64 > HASH_GROUP_WIDTH {align_of::<(K, V)>() as u64} else {HASH_GROUP_WIDTH};
+
+    let Some(data_bytes) = buckets.checked_mul(entry_size) else {return None};
+    let Some(data) = fallible::from_bytes(data_bytes) else {return None};
+    let Some(data) = data.checked_align_up(MemorySize::from_bytes(ctrl_align)) else {return None};
+    //extra group so that probing can wrap around
+    let Some(ctrl) = fallible::from_bytes(buckets + HASH_GROUP_WIDTH) else {return None};
+
+    data.checked_add(ctrl)
+}
+
+/// Calculates the number of entries that a table with `buckets` buckets can hold before it must grow
+const fn hash_map_usable_capacity(buckets: u64) -> u64 {
+    if buckets < 8 {
+        buckets.saturating_sub(1)
+    } else {
+        buckets / 8 * 7
+    }
+}
+
+/// Calculates how many elements a `Vec<T>` can hold within `budget`
+/// 
+/// Zero-sized types never allocate, so any number of them fits
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{MemorySize, estimate};
+///
+/// assert_eq!(estimate::vec_capacity_within::<u32>(MemorySize::from_bytes(402)), 100);
+/// assert_eq!(estimate::vec_capacity_within::<()>(MemorySize::new()), usize::MAX);
+/// ```
+pub const fn vec_capacity_within<T>(budget: MemorySize) -> usize {
+    if size_of::<T>() == 0 {
+        return usize::MAX;
+    }
+    let elements = budget.size_bits_bytes().1 / size_of::<T>() as u64;
+    if elements > usize::MAX as u64 {usize::MAX} else {elements as usize}
+}
+
+/// Calculates how many bytes a `String` can hold within `budget`
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{MemorySize, estimate};
+///
+/// assert_eq!(estimate::string_capacity_within(MemorySize::from_bits(84)), 10);
+/// ```
+pub const fn string_capacity_within(budget: MemorySize) -> usize {
+    vec_capacity_within::<u8>(budget)
+}
+
+/// Calculates how many entries a `HashMap<K, V>` can hold within `budget`
+/// 
+/// This inverts `hash_map_heap_size`, so creating a map with the returned capacity is estimated to fit within `budget`
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{MemorySize, estimate};
+///
+/// let budget = MemorySize::from_bytes(64 * 1024);
+/// let capacity = estimate::hash_map_capacity_within::<u64, u64>(budget);
+/// assert_eq!(capacity, 1792);
+/// assert!(estimate::hash_map_heap_size::<u64, u64>(capacity) <= budget);
+/// assert!(estimate::hash_map_heap_size::<u64, u64>(capacity + 1) > budget);
+/// ```
+pub const fn hash_map_capacity_within<K, V>(budget: MemorySize) -> usize {
+    //find the largest power of two table that fits
+    let mut buckets = 0;
+    let mut next = 4;
+    while next <= (1 << 62) {
+        match hash_map_table_size::<K, V>(next) {
+            Some(size) if size.size_bits() <= budget.size_bits() => {
+                buckets = next;
+                next *= 2;
+            }
+            //larger tables can never fit either
+            _ => break,
+        }
+    }
+
+    let capacity = hash_map_usable_capacity(buckets);
+    if capacity > usize::MAX as u64 {usize::MAX} else {capacity as usize}
+}
@@ -0,0 +1,516 @@
+//! Versions of the `MemorySize` API that return `None` instead of panicking
+//! 
+//! Code that must never panic can use these functions in place of the methods and operators that can,
+//! like `MemorySize::from_bytes` or `+`. Operations that can never panic, like `MemorySize::from_bits`, are not repeated here.
+//! 
+//! This covers the constructors, arithmetic, alignment and splitting methods of `MemorySize` itself.
+//! Calculations built on top of sizes in other modules, like page table overhead or encoded sizes, document their own panics
+
+use crate::{checked_lcm, Chunks, MemorySize, RoundingMode, BITS_IN_BYTE};
+
+/// Constructs a `MemorySize` from a number of bytes
+/// 
+/// Returns `None` if the size in bits does not fit in a `u64`
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::from_bytes(128), Some(MemorySize::from_bits(1024)));
+/// assert_eq!(fallible::from_bytes(u64::MAX), None);
+/// ```
+pub const fn from_bytes(size_bytes: u64) -> Option<MemorySize> {
+    match size_bytes.checked_mul(BITS_IN_BYTE) {
+        Some(size_bits) => Some(MemorySize { size_bits }),
+        None => None,
+    }
+}
+
+/// Constructs a `MemorySize` from a number of bits by rounding up to the next whole byte
+/// 
+/// Returns `None` if rounding up overflows
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::from_bits_ceil(9), Some(MemorySize::from_bytes(2)));
+/// assert_eq!(fallible::from_bits_ceil(u64::MAX), None);
+/// ```
+pub const fn from_bits_ceil(bits: u64) -> Option<MemorySize> {
+    match bits.div_ceil(BITS_IN_BYTE).checked_mul(BITS_IN_BYTE) {
+        Some(size_bits) => Some(MemorySize { size_bits }),
+        None => None,
+    }
+}
+
+/// Returns the size in bytes
+/// 
+/// Returns `None` if `size` is not a whole number of bytes
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::size_bytes(MemorySize::from_bytes(64)), Some(64));
+/// assert_eq!(fallible::size_bytes(MemorySize::from_bits(12)), None);
+/// ```
+pub const fn size_bytes(size: MemorySize) -> Option<u64> {
+    match size.size_bits_bytes() {
+        (0, bytes) => Some(bytes),
+        _ => None,
+    }
+}
+
+/// Adds two sizes
+/// 
+/// Returns `None` if the result overflows
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::add(MemorySize::from_bits(1), MemorySize::from_bits(2)), Some(MemorySize::from_bits(3)));
+/// assert_eq!(fallible::add(MemorySize::from_bits(u64::MAX), MemorySize::from_bits(1)), None);
+/// ```
+pub const fn add(lhs: MemorySize, rhs: MemorySize) -> Option<MemorySize> {
+    lhs.checked_add(rhs)
+}
+
+/// Subtracts `rhs` from `lhs`
+/// 
+/// Returns `None` if `rhs` is larger than `lhs`
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::sub(MemorySize::from_bits(3), MemorySize::from_bits(2)), Some(MemorySize::from_bits(1)));
+/// assert_eq!(fallible::sub(MemorySize::from_bits(2), MemorySize::from_bits(3)), None);
+/// ```
+pub const fn sub(lhs: MemorySize, rhs: MemorySize) -> Option<MemorySize> {
+    lhs.checked_sub(rhs)
+}
+
+/// Adds up every size in `sizes`
+/// 
+/// Returns `None` if the total overflows
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// let sizes = [MemorySize::from_bytes(1), MemorySize::from_bytes(2)];
+/// assert_eq!(fallible::sum(&sizes), Some(MemorySize::from_bytes(3)));
+/// ```
+pub const fn sum(sizes: &[MemorySize]) -> Option<MemorySize> {
+    let mut total = MemorySize::new();
+    let mut i = 0;
+    while i < sizes.len() {
+        total = match add(total, sizes[i]) {
+            Some(total) => total,
+            None => return None,
+        };
+        i += 1;
+    }
+    Some(total)
+}
+
+/// Calculates the memory size above or equal to `size` that is aligned to `alignment`
+/// 
+/// A zero alignment leaves `size` unchanged, like in `MemorySize::align_up`. Returns `None` if rounding up overflows
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// let four = MemorySize::from_bytes(4);
+/// assert_eq!(fallible::align_up(MemorySize::from_bytes(25), four), Some(MemorySize::from_bytes(28)));
+/// assert_eq!(fallible::align_up(MemorySize::from_bits(u64::MAX), four), None);
+/// ```
+pub const fn align_up(size: MemorySize, alignment: MemorySize) -> Option<MemorySize> {
+    size.checked_align_up(alignment)
+}
+
+/// Calculates the memory size above or equal to `size` that is aligned to every alignment in `alignments`
+/// 
+/// Zero alignments are ignored, like in `MemorySize::align_up_all`.
+/// Returns `None` if the least common multiple of the alignments overflows, or rounding up to it does
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// let alignments = [MemorySize::from_bytes(8), MemorySize::from_bytes(12)];
+/// assert_eq!(fallible::align_up_all(MemorySize::from_bytes(100), &alignments), Some(MemorySize::from_bytes(120)));
+///
+/// let coprime = [MemorySize::from_bits(u64::MAX), MemorySize::from_bits(2)];
+/// assert_eq!(fallible::align_up_all(MemorySize::from_bits(1), &coprime), None);
+/// ```
+pub const fn align_up_all(size: MemorySize, alignments: &[MemorySize]) -> Option<MemorySize> {
+    let mut combined = 1;
+    let mut i = 0;
+    while i < alignments.len() {
+        let alignment = alignments[i].size_bits;
+        if alignment != 0 {
+            combined = match checked_lcm(combined, alignment) {
+                Some(combined) => combined,
+                None => return None,
+            };
+        }
+        i += 1;
+    }
+
+    align_up(size, MemorySize { size_bits: combined })
+}
+
+/// Calculates the minimum number of bytes that can store `size`
+/// 
+/// Returns `None` if rounding up overflows
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::round_up_byte(MemorySize::from_bits(12)), Some(MemorySize::from_bytes(2)));
+/// ```
+pub const fn round_up_byte(size: MemorySize) -> Option<MemorySize> {
+    from_bits_ceil(size.size_bits)
+}
+
+/// Multiplies `size` by `rhs`
+/// 
+/// Returns `None` if the result overflows
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::mul(MemorySize::from_bytes(4), 3), Some(MemorySize::from_bytes(12)));
+/// assert_eq!(fallible::mul(MemorySize::from_bits(u64::MAX), 2), None);
+/// ```
+pub const fn mul(size: MemorySize, rhs: u64) -> Option<MemorySize> {
+    size.checked_mul(rhs)
+}
+
+/// Divides `size` into `rhs` parts, rounding down to the nearest bit
+/// 
+/// Returns `None` if `rhs` is zero
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::div(MemorySize::from_bits(7), 2), Some(MemorySize::from_bits(3)));
+/// assert_eq!(fallible::div(MemorySize::from_bits(7), 0), None);
+/// ```
+pub const fn div(size: MemorySize, rhs: u64) -> Option<MemorySize> {
+    match size.size_bits.checked_div(rhs) {
+        Some(size_bits) => Some(MemorySize { size_bits }),
+        None => None,
+    }
+}
+
+/// Calculates how many whole times `rhs` fits into `size`
+/// 
+/// Returns `None` if `rhs` is zero
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// let page = MemorySize::from_bytes(4096);
+/// assert_eq!(fallible::div_size(MemorySize::from_bytes(10_000), page), Some(2));
+/// assert_eq!(fallible::div_size(page, MemorySize::new()), None);
+/// ```
+pub const fn div_size(size: MemorySize, rhs: MemorySize) -> Option<u64> {
+    size.size_bits.checked_div(rhs.size_bits)
+}
+
+/// Calculates the size left over after taking as many whole `rhs` out of `size` as possible
+/// 
+/// Returns `None` if `rhs` is zero
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// let chunk = MemorySize::from_bytes(4096);
+/// assert_eq!(fallible::rem(MemorySize::from_bytes(10_000), chunk), Some(MemorySize::from_bytes(1808)));
+/// assert_eq!(fallible::rem(chunk, MemorySize::new()), None);
+/// ```
+pub const fn rem(size: MemorySize, rhs: MemorySize) -> Option<MemorySize> {
+    size.checked_rem(rhs)
+}
+
+/// Multiplies `size` by `numerator / denominator`, rounding the result to a whole bit with `rounding`
+/// 
+/// Returns `None` if `denominator` is zero, or the result overflows
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize, RoundingMode};
+///
+/// let size = MemorySize::from_bits(100);
+/// assert_eq!(fallible::scale(size, 2, 3, RoundingMode::Nearest), Some(MemorySize::from_bits(67)));
+/// assert_eq!(fallible::scale(size, 2, 0, RoundingMode::Nearest), None);
+/// assert_eq!(fallible::scale(MemorySize::from_bits(u64::MAX), 3, 2, RoundingMode::Floor), None);
+/// ```
+pub const fn scale(size: MemorySize, numerator: u64, denominator: u64, rounding: RoundingMode) -> Option<MemorySize> {
+    if denominator == 0 {
+        return None;
+    }
+    let scaled = rounding.div(size.size_bits as u128 * numerator as u128, denominator as u128);
+    if scaled > u64::MAX as u128 {
+        return None;
+    }
+    Some(MemorySize { size_bits: scaled as u64 })
+}
+
+/// Multiplies `size` by `2^rhs`
+/// 
+/// Returns `None` if the result overflows
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::shl(MemorySize::from_bytes(3), 2), Some(MemorySize::from_bytes(12)));
+/// assert_eq!(fallible::shl(MemorySize::from_bits(1 << 63), 1), None);
+/// ```
+pub const fn shl(size: MemorySize, rhs: u32) -> Option<MemorySize> {
+    size.checked_shl(rhs)
+}
+
+/// Divides `size` by `2^rhs`, rounding down to the nearest bit
+/// 
+/// Returns `None` if `rhs` is 64 or more
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::shr(MemorySize::from_bytes(12), 2), Some(MemorySize::from_bytes(3)));
+/// assert_eq!(fallible::shr(MemorySize::from_bytes(12), 64), None);
+/// ```
+pub const fn shr(size: MemorySize, rhs: u32) -> Option<MemorySize> {
+    size.checked_shr(rhs)
+}
+
+/// Calculates the smallest power of two bits that is at least `size`
+/// 
+/// Returns `None` if the result overflows
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::next_power_of_two(MemorySize::from_bytes(3000)), Some(MemorySize::from_bytes(4096)));
+/// assert_eq!(fallible::next_power_of_two(MemorySize::from_bits(u64::MAX)), None);
+/// ```
+pub const fn next_power_of_two(size: MemorySize) -> Option<MemorySize> {
+    size.checked_next_power_of_two()
+}
+
+/// Calculates the largest power of two bits that is at most `size`
+/// 
+/// Returns `None` if `size` is zero
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::prev_power_of_two(MemorySize::from_bytes(3000)), Some(MemorySize::from_bytes(2048)));
+/// assert_eq!(fallible::prev_power_of_two(MemorySize::new()), None);
+/// ```
+pub const fn prev_power_of_two(size: MemorySize) -> Option<MemorySize> {
+    match ilog2(size) {
+        Some(log) => Some(MemorySize { size_bits: 1 << log }),
+        None => None,
+    }
+}
+
+/// Calculates the base 2 logarithm of the number of bits in `size`, rounded down
+/// 
+/// Returns `None` if `size` is zero
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::ilog2(MemorySize::from_bits(5)), Some(2));
+/// assert_eq!(fallible::ilog2(MemorySize::new()), None);
+/// ```
+pub const fn ilog2(size: MemorySize) -> Option<u32> {
+    size.size_bits.checked_ilog2()
+}
+
+/// Calculates the base 2 logarithm of the number of whole bytes in `size`, rounded down
+/// 
+/// Returns `None` if `size` is smaller than a byte
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::ilog2_bytes(MemorySize::from_bytes(1500)), Some(10));
+/// assert_eq!(fallible::ilog2_bytes(MemorySize::from_bits(7)), None);
+/// ```
+pub const fn ilog2_bytes(size: MemorySize) -> Option<u32> {
+    (size.size_bits / BITS_IN_BYTE).checked_ilog2()
+}
+
+/// Calculates how many blocks of size `block` are needed to hold `size`
+/// 
+/// Returns `None` if `block` is zero
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// let block = MemorySize::from_bytes(4096);
+/// assert_eq!(fallible::div_ceil(MemorySize::from_bytes(4097), block), Some(2));
+/// assert_eq!(fallible::div_ceil(block, MemorySize::new()), None);
+/// ```
+pub const fn div_ceil(size: MemorySize, block: MemorySize) -> Option<u64> {
+    if block.size_bits == 0 {
+        return None;
+    }
+    Some(size.size_bits.div_ceil(block.size_bits))
+}
+
+/// Constructs a `MemorySize` from a number of kibibytes, each 1024 bytes
+/// 
+/// Returns `None` if the size in bits does not fit in a `u64`. The other unit constructors below work in the same way
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// assert_eq!(fallible::from_kib(4), Some(MemorySize::from_bytes(4096)));
+/// assert_eq!(fallible::from_kib(u64::MAX >> 12), None);
+/// ```
+pub const fn from_kib(kib: u64) -> Option<MemorySize> {
+    from_unit(kib, 1 << 10)
+}
+
+/// Constructs a `MemorySize` from a number of mebibytes, or `None` if it overflows like `from_kib`
+pub const fn from_mib(mib: u64) -> Option<MemorySize> {
+    from_unit(mib, 1 << 20)
+}
+
+/// Constructs a `MemorySize` from a number of gibibytes, or `None` if it overflows like `from_kib`
+pub const fn from_gib(gib: u64) -> Option<MemorySize> {
+    from_unit(gib, 1 << 30)
+}
+
+/// Constructs a `MemorySize` from a number of kilobytes, or `None` if it overflows like `from_kib`
+pub const fn from_kb(kb: u64) -> Option<MemorySize> {
+    from_unit(kb, 1_000)
+}
+
+/// Constructs a `MemorySize` from a number of megabytes, or `None` if it overflows like `from_kib`
+pub const fn from_mb(mb: u64) -> Option<MemorySize> {
+    from_unit(mb, 1_000_000)
+}
+
+/// Constructs a `MemorySize` from a number of gigabytes, or `None` if it overflows like `from_kib`
+pub const fn from_gb(gb: u64) -> Option<MemorySize> {
+    from_unit(gb, 1_000_000_000)
+}
+
+/// Constructs a `MemorySize` from `count` units of `unit_bytes` bytes each
+const fn from_unit(count: u64, unit_bytes: u64) -> Option<MemorySize> {
+    match count.checked_mul(unit_bytes) {
+        Some(size_bytes) => from_bytes(size_bytes),
+        None => None,
+    }
+}
+
+/// Calculates the memory size closest to `size` that is aligned to `alignment`, rounding halfway sizes up
+/// 
+/// A zero alignment leaves `size` unchanged, like in `MemorySize::align_nearest`. Returns `None` if rounding up overflows
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// let granule = MemorySize::from_bytes(4);
+/// assert_eq!(fallible::align_nearest(MemorySize::from_bytes(10), granule), Some(MemorySize::from_bytes(12)));
+/// assert_eq!(fallible::align_nearest(MemorySize::from_bits(u64::MAX), granule), None);
+/// ```
+pub const fn align_nearest(size: MemorySize, alignment: MemorySize) -> Option<MemorySize> {
+    if alignment.size_bits == 0 {
+        return Some(size);
+    }
+
+    let remainder = size.size_bits % alignment.size_bits;
+    if remainder < alignment.size_bits - remainder {
+        Some(size.align_down(alignment))
+    } else {
+        size.checked_align_up(alignment)
+    }
+}
+
+/// Splits `size` into the first `at`, and the rest
+/// 
+/// Returns `None` if `at` is larger than `size`
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// let size = MemorySize::from_bytes(100);
+/// assert_eq!(fallible::split_at(size, MemorySize::from_bytes(30)), Some((MemorySize::from_bytes(30), MemorySize::from_bytes(70))));
+/// assert_eq!(fallible::split_at(size, MemorySize::from_bytes(101)), None);
+/// ```
+pub const fn split_at(size: MemorySize, at: MemorySize) -> Option<(MemorySize, MemorySize)> {
+    match size.checked_sub(at) {
+        Some(rest) => Some((at, rest)),
+        None => None,
+    }
+}
+
+/// Splits `size` into chunks of `chunk_size`, where the last chunk is smaller if `chunk_size` does not divide `size` evenly
+/// 
+/// Returns `None` if `chunk_size` is zero
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{fallible, MemorySize};
+///
+/// let chunks: Vec<_> = fallible::chunks(MemorySize::from_bytes(10), MemorySize::from_bytes(4)).unwrap().collect();
+/// assert_eq!(chunks, [4, 4, 2].map(MemorySize::from_bytes));
+/// assert!(fallible::chunks(MemorySize::from_bytes(10), MemorySize::new()).is_none());
+/// ```
+pub const fn chunks(size: MemorySize, chunk_size: MemorySize) -> Option<Chunks> {
+    if chunk_size.size_bits == 0 {
+        return None;
+    }
+    Some(size.chunks(chunk_size))
+}
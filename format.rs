@@ -0,0 +1,910 @@
+//! Alternative ways to format a `MemorySize`, returned by the `display_*` methods
+
+use crate::MemorySize;
+use core::fmt;
+
+/// A unit that sizes can be formatted in, with its names
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct Unit {
+    /// Abbreviated name, like `KiB`
+    pub short: &'static str,
+    /// Full name in the singular, like `kibibyte`
+    pub long: &'static str,
+    /// Full name in the plural, like `kibibytes`
+    pub long_plural: &'static str,
+}
+
+impl Unit {
+    /// Creates a unit from its abbreviated name and full names in the singular and plural
+    pub const fn new(short: &'static str, long: &'static str, long_plural: &'static str) -> Unit {
+        Unit { short, long, long_plural }
+    }
+}
+
+/// Binary (IEC) units, each 1024 times the previous
+pub(crate) const IEC_UNITS: [Unit; 7] = [
+    Unit::new("B", "byte", "bytes"),
+    Unit::new("KiB", "kibibyte", "kibibytes"),
+    Unit::new("MiB", "mebibyte", "mebibytes"),
+    Unit::new("GiB", "gibibyte", "gibibytes"),
+    Unit::new("TiB", "tebibyte", "tebibytes"),
+    Unit::new("PiB", "pebibyte", "pebibytes"),
+    Unit::new("EiB", "exbibyte", "exbibytes"),
+];
+
+/// A single bit
+pub(crate) const BIT_UNIT: Unit = Unit::new("bit", "bit", "bits");
+
+/// The template used when none is specified
+pub const DEFAULT_TEMPLATE: &str = "{value} {unit}";
+
+/// Writes `template` to `f`, replacing the placeholders with `value` and names of `unit`
+///
+/// The placeholders are:
+/// - `{value}`: the number
+/// - `{unit}`: the abbreviated unit, like `KiB`
+/// - `{unit_lower}`: the abbreviated unit in lowercase, like `kib`
+/// - `{unit_long}`: the full unit name, in the plural unless `singular` is set, like `kibibytes`
+///
+/// Any other text, including unknown placeholders, is written as is
+pub(crate) fn write_template(f: &mut dyn fmt::Write, template: &str, value: &dyn fmt::Display, unit: Unit, singular: bool) -> fmt::Result {
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        f.write_str(&rest[..start])?;
+        rest = &rest[start..];
+
+        let placeholder = ["{value}", "{unit}", "{unit_lower}", "{unit_long}"]
+            .into_iter()
+            .find(|placeholder| rest.starts_with(placeholder));
+
+        match placeholder {
+            Some("{value}") => write!(f, "{}", value)?,
+            Some("{unit}") => f.write_str(unit.short)?,
+            Some("{unit_lower}") => {
+                for c in unit.short.chars().flat_map(char::to_lowercase) {
+                    f.write_char(c)?;
+                }
+            }
+            Some(_) => f.write_str(if singular {unit.long} else {unit.long_plural})?,
+            None => {
+                f.write_str("{")?;
+                rest = &rest[1..];
+                continue;
+            }
+        }
+        rest = &rest[placeholder.unwrap().len()..];
+    }
+    f.write_str(rest)
+}
+
+/// Counts the characters written to it
+struct CharCount(usize);
+
+impl fmt::Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes the output of `write` to `f`, padded to the width of `f` with its fill character and alignment
+///
+/// Sizes are right-aligned by default like numbers, and the `0` flag pads with zeros before the number
+pub(crate) fn write_padded(f: &mut fmt::Formatter<'_>, write: impl Fn(&mut dyn fmt::Write) -> fmt::Result) -> fmt::Result {
+    let Some(width) = f.width() else {return write(f)};
+
+    let mut count = CharCount(0);
+    write(&mut count)?;
+    let padding = width.saturating_sub(count.0);
+
+    let (fill, align) = if f.sign_aware_zero_pad() {
+        ('0', fmt::Alignment::Right)
+    } else {
+        (f.fill(), f.align().unwrap_or(fmt::Alignment::Right))
+    };
+    let (before, after) = match align {
+        fmt::Alignment::Left => (0, padding),
+        fmt::Alignment::Right => (padding, 0),
+        fmt::Alignment::Center => (padding / 2, padding - padding / 2),
+    };
+
+    for _ in 0..before {
+        fmt::Write::write_char(f, fill)?;
+    }
+    write(f)?;
+    for _ in 0..after {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
+
+/// A number with `separator` between each group of three digits, if there is a separator
+struct Grouped {
+    value: u64,
+    separator: Option<char>,
+}
+
+impl fmt::Display for Grouped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(separator) = self.separator else {return write!(f, "{}", self.value)};
+
+        let mut group = 1;
+        while self.value / group >= 1000 {
+            group *= 1000;
+        }
+        write!(f, "{}", self.value / group)?;
+        while group > 1 {
+            group /= 1000;
+            write!(f, "{}{:03}", separator, self.value / group % 1000)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats a `MemorySize` as a plain number of bytes, or of bits if it is not a whole number of bytes
+///
+/// Created by `MemorySize::display_exact`
+#[derive(Clone, Copy, Debug)]
+pub struct ExactDisplay {
+    size: MemorySize,
+    separator: Option<char>,
+}
+
+impl MemorySize {
+    /// Returns a value that formats `&self` as a plain number of bytes without scaling, like `1048576 B`
+    ///
+    /// Sizes that are not a whole number of bytes are formatted in bits.
+    /// This is the same as the alternate form `{:#}` of the `Display` implementation of `MemorySize`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(1 << 20).display_exact().to_string(), "1048576 B");
+    /// assert_eq!(MemorySize::from_bits(12).display_exact().to_string(), "12 bits");
+    /// ```
+    pub const fn display_exact(&self) -> ExactDisplay {
+        ExactDisplay { size: *self, separator: None }
+    }
+}
+
+impl ExactDisplay {
+    /// Writes `separator` between each group of three digits
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let size = MemorySize::from_bytes(1 << 20);
+    /// assert_eq!(size.display_exact().grouped(',').to_string(), "1,048,576 B");
+    /// assert_eq!(size.display_exact().grouped('_').to_string(), "1_048_576 B");
+    /// ```
+    pub const fn grouped(self, separator: char) -> ExactDisplay {
+        ExactDisplay { separator: Some(separator), ..self }
+    }
+
+    fn write(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        let bits = self.size.size_bits;
+        let (value, unit) = match bits {
+            _ if bits.is_multiple_of(crate::BITS_IN_BYTE) => (bits / crate::BITS_IN_BYTE, "B"),
+            1 => (1, "bit"),
+            _ => (bits, "bits"),
+        };
+        write!(f, "{} {}", Grouped { value, separator: self.separator }, unit)
+    }
+}
+
+impl fmt::Display for ExactDisplay {
+    /// Formats the size, padded to the width of the formatter
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_padded(f, |out| self.write(out))
+    }
+}
+
+/// Formats a `MemorySize` exactly, so that parsing the output gives back the same size
+///
+/// Created by `MemorySize::display_round_trip`
+#[derive(Clone, Copy, Debug)]
+pub struct RoundTripDisplay<'a> {
+    size: MemorySize,
+    template: &'a str,
+}
+
+impl MemorySize {
+    /// Returns a value that formats `&self` exactly, using the largest binary unit that represents it without a fraction
+    ///
+    /// Sizes that are not a whole number of bytes are formatted in bits.
+    /// Unlike human-readable formatting, nothing is rounded, so the output is safe to write to files that are read back later
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(4096).display_round_trip().to_string(), "4 KiB");
+    /// assert_eq!(MemorySize::from_bytes(4097).display_round_trip().to_string(), "4097 B");
+    /// assert_eq!(MemorySize::from_bits(12).display_round_trip().to_string(), "12 bit");
+    /// ```
+    pub const fn display_round_trip(&self) -> RoundTripDisplay<'static> {
+        RoundTripDisplay { size: *self, template: DEFAULT_TEMPLATE }
+    }
+}
+
+impl<'a> RoundTripDisplay<'a> {
+    /// Formats using `template` instead of `"{value} {unit}"`
+    ///
+    /// The template can contain `{value}`, `{unit}`, `{unit_lower}` and `{unit_long}` placeholders,
+    /// and any other text is written as is. Note that custom unit spellings may not parse back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let size = MemorySize::from_bytes(2 << 20);
+    /// assert_eq!(size.display_round_trip().with_template("{value}{unit}").to_string(), "2MiB");
+    /// assert_eq!(size.display_round_trip().with_template("{value} {unit_long}").to_string(), "2 mebibytes");
+    /// assert_eq!(size.display_round_trip().with_template("{value}{unit_lower}").to_string(), "2mib");
+    /// ```
+    pub const fn with_template<'b>(&self, template: &'b str) -> RoundTripDisplay<'b> {
+        RoundTripDisplay { size: self.size, template }
+    }
+}
+
+impl RoundTripDisplay<'_> {
+    fn write(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        let (bits, mut value) = self.size.size_bits_bytes();
+        if bits != 0 {
+            let bits = self.size.size_bits();
+            return write_template(f, self.template, &bits, BIT_UNIT, bits == 1);
+        }
+
+        let mut unit = 0;
+        while value != 0 && value % 1024 == 0 && unit + 1 < IEC_UNITS.len() {
+            value /= 1024;
+            unit += 1;
+        }
+        write_template(f, self.template, &value, IEC_UNITS[unit], value == 1)
+    }
+}
+
+impl fmt::Display for RoundTripDisplay<'_> {
+    /// Formats the size, padded to the width of the formatter
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_padded(f, |out| self.write(out))
+    }
+}
+
+/// Which prefixes human-readable sizes are scaled with
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug, Default)]
+pub enum UnitSystem {
+    /// Binary prefixes, each 1024 times the previous, like `KiB` and `MiB`
+    #[default]
+    Iec,
+    /// Decimal prefixes, each 1000 times the previous, like `kB` and `MB`
+    Si,
+}
+
+impl UnitSystem {
+    /// Returns the multiple between consecutive units
+    const fn base(&self) -> u128 {
+        match self {
+            UnitSystem::Iec => 1024,
+            UnitSystem::Si => 1000,
+        }
+    }
+
+    /// Returns the units, starting from bytes, or from bits if `bits` is set
+    #[cfg(not(feature = "i18n"))]
+    const fn units(&self, bits: bool) -> &'static [Unit; 7] {
+        match (self, bits) {
+            (UnitSystem::Iec, false) => &IEC_UNITS,
+            (UnitSystem::Si, false) => &SI_UNITS,
+            (UnitSystem::Iec, true) => &IEC_BIT_UNITS,
+            (UnitSystem::Si, true) => &SI_BIT_UNITS,
+        }
+    }
+}
+
+/// Decimal (SI) units, each 1000 times the previous
+pub(crate) const SI_UNITS: [Unit; 7] = [
+    Unit::new("B", "byte", "bytes"),
+    Unit::new("kB", "kilobyte", "kilobytes"),
+    Unit::new("MB", "megabyte", "megabytes"),
+    Unit::new("GB", "gigabyte", "gigabytes"),
+    Unit::new("TB", "terabyte", "terabytes"),
+    Unit::new("PB", "petabyte", "petabytes"),
+    Unit::new("EB", "exabyte", "exabytes"),
+];
+
+/// Binary (IEC) bit units, each 1024 times the previous
+pub(crate) const IEC_BIT_UNITS: [Unit; 7] = [
+    BIT_UNIT,
+    Unit::new("Kibit", "kibibit", "kibibits"),
+    Unit::new("Mibit", "mebibit", "mebibits"),
+    Unit::new("Gibit", "gibibit", "gibibits"),
+    Unit::new("Tibit", "tebibit", "tebibits"),
+    Unit::new("Pibit", "pebibit", "pebibits"),
+    Unit::new("Eibit", "exbibit", "exbibits"),
+];
+
+/// Decimal (SI) bit units, each 1000 times the previous
+pub(crate) const SI_BIT_UNITS: [Unit; 7] = [
+    BIT_UNIT,
+    Unit::new("kbit", "kilobit", "kilobits"),
+    Unit::new("Mbit", "megabit", "megabits"),
+    Unit::new("Gbit", "gigabit", "gigabits"),
+    Unit::new("Tbit", "terabit", "terabits"),
+    Unit::new("Pbit", "petabit", "petabits"),
+    Unit::new("Ebit", "exabit", "exabits"),
+];
+
+/// The most decimal places human-readable sizes can be formatted with
+pub const MAX_PRECISION: usize = 18;
+
+/// Options for human-readable formatting, used by `MemorySize::display_with`
+///
+/// # Examples
+///
+/// ```
+/// use memory_size::format::{FormatOptions, UnitSystem};
+/// use memory_size::MemorySize;
+///
+/// let options = FormatOptions::new().units(UnitSystem::Si).precision(1);
+/// assert_eq!(MemorySize::from_bytes(1_250_000).display_with(options).to_string(), "1.3 MB");
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct FormatOptions {
+    units: UnitSystem,
+    precision: usize,
+    bits: bool,
+    #[cfg(feature = "i18n")]
+    locale: &'static crate::i18n::Locale,
+}
+
+impl FormatOptions {
+    /// Creates the default options: binary byte units with two decimal places
+    pub const fn new() -> FormatOptions {
+        FormatOptions {
+            units: UnitSystem::Iec,
+            precision: 2,
+            bits: false,
+            #[cfg(feature = "i18n")]
+            locale: &crate::i18n::Locale::ENGLISH,
+        }
+    }
+
+    /// Sets whether sizes are scaled with binary or decimal prefixes
+    pub const fn units(self, units: UnitSystem) -> FormatOptions {
+        FormatOptions { units, ..self }
+    }
+
+    /// Sets the number of decimal places, which is limited to `MAX_PRECISION`
+    pub const fn precision(self, precision: usize) -> FormatOptions {
+        let precision = if precision > MAX_PRECISION {MAX_PRECISION} else {precision};
+        FormatOptions { precision, ..self }
+    }
+
+    /// Sets whether sizes are counted in bit units, like `Mbit`, instead of byte units
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::format::{FormatOptions, UnitSystem};
+    /// use memory_size::MemorySize;
+    ///
+    /// let options = FormatOptions::new().bits(true);
+    /// assert_eq!(MemorySize::from_bits(3 << 20).display_with(options).to_string(), "3.00 Mibit");
+    /// ```
+    pub const fn bits(self, bits: bool) -> FormatOptions {
+        FormatOptions { bits, ..self }
+    }
+
+    /// Sets the language of the unit names and decimal separator
+    ///
+    /// Sizes below one kilobyte or kibibyte are also written in the locale, so they might not parse back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::format::FormatOptions;
+    /// use memory_size::i18n::Locale;
+    /// use memory_size::MemorySize;
+    ///
+    /// let options = FormatOptions::new().locale(&Locale::FRENCH);
+    /// assert_eq!(MemorySize::from_bytes(1536).display_with(options).to_string(), "1,50 Kio");
+    /// ```
+    #[cfg(feature = "i18n")]
+    pub const fn locale(self, locale: &'static crate::i18n::Locale) -> FormatOptions {
+        FormatOptions { locale, ..self }
+    }
+
+    /// Returns the units to scale with, starting from a byte, or from a bit if `bits` is set
+    const fn unit_table(&self, bits: bool) -> &'static [Unit; 7] {
+        #[cfg(feature = "i18n")]
+        let table = self.locale.units(self.units, bits);
+        #[cfg(not(feature = "i18n"))]
+        let table = self.units.units(bits);
+        table
+    }
+
+    /// Returns the number of bits in the unit at index `unit` of the unit table
+    const fn unit_bits(&self, unit: usize) -> u128 {
+        let byte_bits = if self.bits {1} else {crate::BITS_IN_BYTE as u128};
+        byte_bits * self.units.base().pow(unit as u32)
+    }
+
+    /// Returns `bits` in the unit at index `unit`, scaled up by `10^precision` and rounded
+    const fn scale(&self, bits: u128, unit: usize, precision: usize) -> u128 {
+        let unit_bits = self.unit_bits(unit);
+        (bits * 10u128.pow(precision as u32) + unit_bits / 2) / unit_bits
+    }
+
+    /// Finds the index of the largest unit that `bits` is not below after rounding to `precision` decimal places
+    ///
+    /// Sizes below the first prefixed unit give zero, as they are written exactly
+    const fn unit_for(&self, bits: u128, precision: usize) -> usize {
+        if bits < self.unit_bits(1) {
+            return 0;
+        }
+
+        let mut unit = 1;
+        while unit + 1 < IEC_UNITS.len() && bits >= self.unit_bits(unit + 1) {
+            unit += 1;
+        }
+        //rounding can reach the next unit, like 1023.999 KiB becoming 1024.00 KiB
+        if self.scale(bits, unit, precision) >= self.units.base() * 10u128.pow(precision as u32) && unit + 1 < IEC_UNITS.len() {
+            unit += 1;
+        }
+        unit
+    }
+
+    /// Writes `bits` with `template` in the unit at index `unit`, rounded to `precision` decimal places
+    ///
+    /// The unit at index zero is written exactly, in bits if `bits` is not a whole number of bytes
+    fn write_in(&self, f: &mut dyn fmt::Write, template: &str, bits: u128, unit: usize, precision: usize) -> fmt::Result {
+        let units = self.unit_table(self.bits);
+        let byte_bits = self.unit_bits(0);
+
+        if unit == 0 && bits.is_multiple_of(byte_bits) {
+            let value = bits / byte_bits;
+            return write_template(f, template, &value, units[0], value == 1);
+        }
+        if unit == 0 {
+            return write_template(f, template, &bits, self.unit_table(true)[0], bits == 1);
+        }
+
+        let scaled = self.scale(bits, unit, precision);
+        let singular = precision == 0 && scaled == 1;
+        let value = Fixed { scaled, precision, separator: self.decimal_separator() };
+        write_template(f, template, &value, units[unit], singular)
+    }
+
+    /// Returns the character between the integer and fractional parts of numbers
+    const fn decimal_separator(&self) -> char {
+        #[cfg(feature = "i18n")]
+        let separator = self.locale.decimal_separator;
+        #[cfg(not(feature = "i18n"))]
+        let separator = '.';
+        separator
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions::new()
+    }
+}
+
+/// Formats a `MemorySize` for humans, rounded in the largest unit that is not above the size
+///
+/// Created by `MemorySize::display_human` and `MemorySize::display_with`
+#[derive(Clone, Copy, Debug)]
+pub struct HumanDisplay<'a> {
+    size: MemorySize,
+    template: &'a str,
+    options: FormatOptions,
+}
+
+/// A number scaled up by `10^precision`, formatted with `precision` decimal places after `separator`
+struct Fixed {
+    scaled: u128,
+    precision: usize,
+    separator: char,
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.precision == 0 {
+            return write!(f, "{}", self.scaled);
+        }
+        let divisor = 10u128.pow(self.precision as u32);
+        write!(f, "{}{}{:0width$}", self.scaled / divisor, self.separator, self.scaled % divisor, width = self.precision)
+    }
+}
+
+impl MemorySize {
+    /// Returns a value that formats `&self` for humans with two decimal places, like `1.50 KiB`
+    ///
+    /// This is the same as `display_with(FormatOptions::new())`, and as the `Display` implementation of `MemorySize`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(1536).display_human().to_string(), "1.50 KiB");
+    /// assert_eq!(MemorySize::from_bytes(5 << 30).display_human().to_string(), "5.00 GiB");
+    /// assert_eq!(MemorySize::from_bytes(512).display_human().to_string(), "512 B");
+    /// ```
+    pub const fn display_human(&self) -> HumanDisplay<'static> {
+        self.display_with(FormatOptions::new())
+    }
+
+    /// Returns a value that formats `&self` for humans, with the units and number of decimal places given by `options`
+    ///
+    /// Sizes of at least one kilobyte or kibibyte are rounded in the largest unit that is not above the size,
+    /// and smaller sizes are written exactly in bytes, or in bits if they are not a whole number of bytes.
+    /// With bit units, sizes below one kilobit or kibibit are written exactly in bits.
+    /// Only integer arithmetic is used, so the output is identical on every platform, including ones without floating point
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::format::{FormatOptions, UnitSystem};
+    /// use memory_size::MemorySize;
+    ///
+    /// let size = MemorySize::from_bytes(1536);
+    /// assert_eq!(size.display_with(FormatOptions::new().precision(0)).to_string(), "2 KiB");
+    /// assert_eq!(size.display_with(FormatOptions::new().units(UnitSystem::Si)).to_string(), "1.54 kB");
+    /// ```
+    pub const fn display_with(&self, options: FormatOptions) -> HumanDisplay<'static> {
+        HumanDisplay { size: *self, template: DEFAULT_TEMPLATE, options }
+    }
+
+    /// Returns a value that formats `&self` in decimal bit units with two decimal places, like `100.00 Mbit`, as is usual for network speeds
+    ///
+    /// This is the same as `display_with(FormatOptions::new().units(UnitSystem::Si).bits(true))`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bits(100_000_000).display_bits().to_string(), "100.00 Mbit");
+    /// assert_eq!(format!("{:.1}", MemorySize::from_bits(1_500_000_000).display_bits()), "1.5 Gbit");
+    /// assert_eq!(MemorySize::from_bytes(100).display_bits().to_string(), "800 bit");
+    /// ```
+    pub const fn display_bits(&self) -> HumanDisplay<'static> {
+        self.display_with(FormatOptions::new().units(UnitSystem::Si).bits(true))
+    }
+
+    /// Returns a value that formats `&self` in binary units with two decimal places, like `1.50 KiB`
+    ///
+    /// This is the same as `display_human`, for call sites that should state the units they use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(1_500_000).display_iec().to_string(), "1.43 MiB");
+    /// ```
+    pub const fn display_iec(&self) -> HumanDisplay<'static> {
+        self.display_with(FormatOptions::new().units(UnitSystem::Iec))
+    }
+
+    /// Returns a value that formats `&self` in decimal units with two decimal places, like `1.50 kB`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(1_500_000).display_si().to_string(), "1.50 MB");
+    /// ```
+    pub const fn display_si(&self) -> HumanDisplay<'static> {
+        self.display_with(FormatOptions::new().units(UnitSystem::Si))
+    }
+}
+
+impl<'a> HumanDisplay<'a> {
+    /// Formats using `template` instead of `"{value} {unit}"`
+    ///
+    /// See `RoundTripDisplay::with_template` for the placeholders
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let size = MemorySize::from_bytes(1536);
+    /// assert_eq!(size.display_human().with_template("{value}{unit_lower}").to_string(), "1.50kib");
+    /// ```
+    pub const fn with_template<'b>(&self, template: &'b str) -> HumanDisplay<'b> {
+        HumanDisplay { size: self.size, template, options: self.options }
+    }
+}
+
+impl HumanDisplay<'_> {
+    fn write(&self, f: &mut dyn fmt::Write, precision: usize) -> fmt::Result {
+        let bits = self.size.size_bits() as u128;
+        let unit = self.options.unit_for(bits, precision);
+        self.options.write_in(f, self.template, bits, unit, precision)
+    }
+}
+
+impl fmt::Display for HumanDisplay<'_> {
+    /// Formats the size, padded to the width of the formatter
+    ///
+    /// The precision of the formatter, like in `{:.1}`, overrides the precision of the options
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().map_or(self.options.precision, |precision| precision.min(MAX_PRECISION));
+        write_padded(f, |out| self.write(out, precision))
+    }
+}
+
+/// Formats how much of a total size is used, like `0.50 GiB / 1.00 GiB (50.0%)`
+///
+/// Created by `MemorySize::progress`
+#[derive(Clone, Copy, Debug)]
+pub struct Progress {
+    used: MemorySize,
+    total: MemorySize,
+    options: FormatOptions,
+}
+
+impl MemorySize {
+    /// Returns a value that formats `&self` as the used part of `total`, with both sizes in the same unit and the percentage used
+    ///
+    /// The unit is chosen from the larger of the two sizes. The percentage is left out if `total` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let used = MemorySize::from_bytes(512 << 20);
+    /// assert_eq!(used.progress(MemorySize::from_bytes(1 << 30)).to_string(), "0.50 GiB / 1.00 GiB (50.0%)");
+    /// assert_eq!(format!("{:.0}", used.progress(MemorySize::from_bytes(3 << 30))), "1 GiB / 3 GiB (16.7%)");
+    /// ```
+    pub const fn progress(&self, total: MemorySize) -> Progress {
+        Progress { used: *self, total, options: FormatOptions::new() }
+    }
+}
+
+impl Progress {
+    /// Formats the sizes with `options` instead of the default binary units with two decimal places
+    pub const fn with_options(self, options: FormatOptions) -> Progress {
+        Progress { options, ..self }
+    }
+
+    fn write(&self, f: &mut dyn fmt::Write, precision: usize) -> fmt::Result {
+        let used = self.used.size_bits as u128;
+        let total = self.total.size_bits as u128;
+        let unit = self.options.unit_for(used.max(total), precision);
+
+        self.options.write_in(f, DEFAULT_TEMPLATE, used, unit, precision)?;
+        f.write_str(" / ")?;
+        self.options.write_in(f, DEFAULT_TEMPLATE, total, unit, precision)?;
+        if let Some(permille) = (used * 1000 + total / 2).checked_div(total) {
+            write!(f, " ({}%)", Fixed { scaled: permille, precision: 1, separator: self.options.decimal_separator() })?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Progress {
+    /// Formats the sizes, padded to the width of the formatter
+    ///
+    /// The precision of the formatter overrides the precision of the options for the sizes, but not for the percentage
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().map_or(self.options.precision, |precision| precision.min(MAX_PRECISION));
+        write_padded(f, |out| self.write(out, precision))
+    }
+}
+
+/// Formats the change from one size to another with an explicit sign, like `+12.50 MiB` or `-3.00 KiB`
+///
+/// Created by `MemorySize::delta_display`
+#[derive(Clone, Copy, Debug)]
+pub struct DeltaDisplay {
+    before: MemorySize,
+    after: MemorySize,
+    options: FormatOptions,
+}
+
+impl MemorySize {
+    /// Returns a value that formats the change from `before` to `after`, starting with `-` if it shrank and `+` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let before = MemorySize::from_bytes(4 << 20);
+    /// let after = MemorySize::from_bytes((33 << 20) / 2);
+    /// assert_eq!(MemorySize::delta_display(before, after).to_string(), "+12.50 MiB");
+    /// assert_eq!(format!("{:.0}", MemorySize::delta_display(MemorySize::from_bytes(4096), MemorySize::from_bytes(1024))), "-3 KiB");
+    /// ```
+    pub const fn delta_display(before: MemorySize, after: MemorySize) -> DeltaDisplay {
+        DeltaDisplay { before, after, options: FormatOptions::new() }
+    }
+}
+
+impl DeltaDisplay {
+    /// Formats the change with `options` instead of the default binary units with two decimal places
+    pub const fn with_options(self, options: FormatOptions) -> DeltaDisplay {
+        DeltaDisplay { options, ..self }
+    }
+
+    fn write(&self, f: &mut dyn fmt::Write, precision: usize) -> fmt::Result {
+        f.write_str(if self.after < self.before {"-"} else {"+"})?;
+
+        let change = self.before.abs_diff(self.after).size_bits as u128;
+        let unit = self.options.unit_for(change, precision);
+        self.options.write_in(f, DEFAULT_TEMPLATE, change, unit, precision)
+    }
+}
+
+impl fmt::Display for DeltaDisplay {
+    /// Formats the change, padded to the width of the formatter
+    ///
+    /// The precision of the formatter, like in `{:.1}`, overrides the precision of the options
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().map_or(self.options.precision, |precision| precision.min(MAX_PRECISION));
+        write_padded(f, |out| self.write(out, precision))
+    }
+}
+
+/// Formats a `MemorySize` as a number of disk sectors or blocks, like `2048 sectors` or `256 blocks @4KiB`
+///
+/// Created by `MemorySize::display_sectors` and `MemorySize::display_blocks`
+#[derive(Clone, Copy, Debug)]
+pub struct BlockDisplay {
+    size: MemorySize,
+    block_size: MemorySize,
+    sectors: bool,
+}
+
+impl MemorySize {
+    /// Returns a value that formats the number of 512 byte sectors needed to hold `&self`, like `2048 sectors`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(1 << 20).display_sectors().to_string(), "2048 sectors");
+    /// assert_eq!(MemorySize::from_bytes(100).display_sectors().to_string(), "1 sector");
+    /// ```
+    pub const fn display_sectors(&self) -> BlockDisplay {
+        BlockDisplay { size: *self, block_size: MemorySize::SECTOR_512, sectors: true }
+    }
+
+    /// Returns a value that formats the number of blocks of `block_size` needed to hold `&self`, followed by the block size
+    ///
+    /// # Panics
+    /// if `block_size` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let block = MemorySize::from_bytes(4096);
+    /// assert_eq!(MemorySize::from_bytes(1 << 20).display_blocks(block).to_string(), "256 blocks @4KiB");
+    /// assert_eq!(MemorySize::from_bytes(5000).display_blocks(block).to_string(), "2 blocks @4KiB");
+    /// ```
+    pub const fn display_blocks(&self, block_size: MemorySize) -> BlockDisplay {
+        assert!(block_size.size_bits != 0, "block size must not be zero");
+        BlockDisplay { size: *self, block_size, sectors: false }
+    }
+}
+
+impl BlockDisplay {
+    fn write(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        let count = self.size.to_sectors_ceil(self.block_size);
+        let plural = if count == 1 {""} else {"s"};
+        if self.sectors {
+            write!(f, "{} sector{}", count, plural)
+        } else {
+            write!(f, "{} block{} @{}", count, plural, self.block_size.display_round_trip().with_template("{value}{unit}"))
+        }
+    }
+}
+
+impl fmt::Display for BlockDisplay {
+    /// Formats the number of sectors or blocks, padded to the width of the formatter
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_padded(f, |out| self.write(out))
+    }
+}
+
+/// Formats `sizes` for a column of a table, right-aligned to the width of the longest
+///
+/// If `shared_unit` is set, every size is written in the unit of the largest one, so the units line up as well
+///
+/// # Examples
+///
+/// ```
+/// use memory_size::format::{column, FormatOptions};
+/// use memory_size::MemorySize;
+///
+/// let sizes = [MemorySize::from_bytes(3), MemorySize::from_bytes(1288490189)];
+/// assert_eq!(column(&sizes, FormatOptions::new(), false), ["     3 B", "1.20 GiB"]);
+/// assert_eq!(column(&sizes, FormatOptions::new(), true), ["0.00 GiB", "1.20 GiB"]);
+/// ```
+#[cfg(feature = "std")]
+pub fn column(sizes: &[MemorySize], options: FormatOptions, shared_unit: bool) -> Vec<String> {
+    let largest = sizes.iter().max().map_or(0, |size| size.size_bits as u128);
+    let unit = options.unit_for(largest, options.precision);
+
+    let cells: Vec<String> = sizes.iter().map(|size| {
+        if shared_unit {
+            let mut cell = String::new();
+            //writing to a string never fails
+            options.write_in(&mut cell, DEFAULT_TEMPLATE, size.size_bits as u128, unit, options.precision).unwrap();
+            cell
+        } else {
+            size.display_with(options).to_string()
+        }
+    }).collect();
+
+    let width = cells.iter().map(|cell| cell.chars().count()).max().unwrap_or(0);
+    cells.iter().map(|cell| format!("{:>width$}", cell)).collect()
+}
+
+/// Implements radix formatting traits for `MemorySize`
+macro_rules! impl_radix {
+    ($($t:ident),*) => {
+        $(
+            impl fmt::$t for MemorySize {
+                /// Formats the number of bytes in the radix of the trait, honoring the flags of the formatter
+                ///
+                /// Sizes that are not a whole number of bytes are formatted as the number of bits, followed by ` bits`
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let bits = self.size_bits;
+                    if bits.is_multiple_of(crate::BITS_IN_BYTE) {
+                        fmt::$t::fmt(&(bits / crate::BITS_IN_BYTE), f)
+                    } else {
+                        fmt::$t::fmt(&bits, f)?;
+                        f.write_str(" bits")
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_radix!(LowerHex, UpperHex, Binary, Octal);
+
+/// Implements exponent formatting traits for `MemorySize`, with the format strings for the exponent style
+macro_rules! impl_exp {
+    ($($t:ident => $spec:literal, $precise_spec:literal);*) => {
+        $(
+            impl fmt::$t for MemorySize {
+                /// Formats the number of bytes in scientific notation followed by ` B`, like `1.0486e6 B` for `{:.4e}`
+                ///
+                /// Sizes that are not a whole number of bytes are formatted as the number of bits, followed by ` bits`.
+                /// The width, fill and alignment of the formatter apply to the whole output
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let bits = self.size_bits;
+                    let (value, unit) = if bits.is_multiple_of(crate::BITS_IN_BYTE) {(bits / crate::BITS_IN_BYTE, "B")} else {(bits, "bits")};
+                    let precision = f.precision();
+                    write_padded(f, |out| {
+                        match precision {
+                            Some(precision) => write!(out, $precise_spec, precision, value)?,
+                            None => write!(out, $spec, value)?,
+                        }
+                        write!(out, " {}", unit)
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_exp!(LowerExp => "{:e}", "{:.*e}"; UpperExp => "{:E}", "{:.*E}");
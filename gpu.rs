@@ -0,0 +1,73 @@
+use crate::{MemorySize, SizeAlign};
+
+/// Memory layout rules for GPU buffer blocks
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum GpuLayout {
+    /// The layout used by uniform buffers, where array elements and structs are aligned to 16 bytes
+    Std140,
+    /// The layout used by storage buffers, where array elements are only aligned to their own alignment
+    Std430,
+}
+
+/// Alignment that std140 applies to array elements and structs, the size of a `vec4`
+const STD140_ROUNDING: MemorySize = MemorySize::from_bytes(16);
+
+impl GpuLayout {
+    /// Calculates the alignment of an array element or struct member with the alignment `align`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{GpuLayout, MemorySize};
+    ///
+    /// let float = MemorySize::from_bytes(4);
+    /// assert_eq!(GpuLayout::Std140.element_align(float), MemorySize::from_bytes(16));
+    /// assert_eq!(GpuLayout::Std430.element_align(float), float);
+    /// ```
+    pub const fn element_align(&self, align: MemorySize) -> MemorySize {
+        match self {
+            GpuLayout::Std140 if align.size_bits() < STD140_ROUNDING.size_bits() => STD140_ROUNDING,
+            _ => align,
+        }
+    }
+
+    /// Calculates the stride between elements of an array of `element`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{GpuLayout, MemorySize, SizeAlign};
+    ///
+    /// // float[] or vec3[]
+    /// let float = SizeAlign::new(MemorySize::from_bytes(4), MemorySize::from_bytes(4));
+    /// let vec3 = SizeAlign::new(MemorySize::from_bytes(12), MemorySize::from_bytes(16));
+    ///
+    /// assert_eq!(GpuLayout::Std140.array_stride(float), MemorySize::from_bytes(16));
+    /// assert_eq!(GpuLayout::Std430.array_stride(float), MemorySize::from_bytes(4));
+    /// assert_eq!(GpuLayout::Std430.array_stride(vec3), MemorySize::from_bytes(16));
+    /// ```
+    pub const fn array_stride(&self, element: SizeAlign) -> MemorySize {
+        element.size().align_up(self.element_align(element.align()))
+    }
+}
+
+impl MemorySize {
+    /// The default minimum alignment of dynamic uniform and storage buffer offsets in wgpu, and the largest value Vulkan allows
+    pub const GPU_BUFFER_OFFSET_ALIGNMENT: MemorySize = MemorySize::from_bytes(256);
+
+    /// Calculates the stride between consecutive bindings of `&self` in a buffer used with dynamic offsets
+    /// 
+    /// `min_offset_alignment` is the device's minimum uniform or storage buffer offset alignment
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let uniforms = MemorySize::from_bytes(80);
+    /// assert_eq!(uniforms.gpu_dynamic_offset_stride(MemorySize::GPU_BUFFER_OFFSET_ALIGNMENT), MemorySize::from_bytes(256));
+    /// ```
+    pub const fn gpu_dynamic_offset_stride(&self, min_offset_alignment: MemorySize) -> MemorySize {
+        self.align_up(min_offset_alignment)
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{MemorySize, BITS_IN_BYTE};
+
+/// Strategies for growing a buffer when it runs out of space
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum GrowthPolicy {
+    /// Double the size on each growth, as `Vec` does
+    Double,
+    /// Multiply the size by 1.5 on each growth, rounding up
+    OneAndHalf,
+    /// Add a constant amount on each growth
+    FixedStep(MemorySize),
+}
+
+impl MemorySize {
+    /// Calculates the size that a buffer of size `&self` should grow to, according to `policy`
+    /// 
+    /// The result is rounded up to a whole number of bytes, and is always at least one byte larger than `&self`,
+    /// so that growing an empty buffer makes progress
+    /// 
+    /// Returns `None` if the new size would overflow
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, GrowthPolicy};
+    ///
+    /// let size = MemorySize::from_bytes(10);
+    /// assert_eq!(size.next_grow(GrowthPolicy::Double), Some(MemorySize::from_bytes(20)));
+    /// assert_eq!(size.next_grow(GrowthPolicy::OneAndHalf), Some(MemorySize::from_bytes(15)));
+    /// assert_eq!(size.next_grow(GrowthPolicy::FixedStep(MemorySize::from_bytes(4))), Some(MemorySize::from_bytes(14)));
+    ///
+    /// assert_eq!(MemorySize::new().next_grow(GrowthPolicy::Double), Some(MemorySize::from_bytes(1)));
+    /// assert_eq!(MemorySize::from_bits(u64::MAX).next_grow(GrowthPolicy::Double), None);
+    /// ```
+    pub const fn next_grow(&self, policy: GrowthPolicy) -> Option<MemorySize> {
+        let current = self.size_bits;
+        let grown = match policy {
+            GrowthPolicy::Double => current.checked_mul(2),
+            GrowthPolicy::OneAndHalf => current.checked_add(current.div_ceil(2)),
+            GrowthPolicy::FixedStep(step) => current.checked_add(step.size_bits),
+        };
+        let Some(grown) = grown else {return None;};
+
+        //grow by at least a byte
+        let Some(minimum) = current.checked_add(BITS_IN_BYTE) else {return None;};
+        let grown = if grown < minimum {minimum} else {grown};
+
+        //round up to a whole byte
+        match grown.checked_next_multiple_of(BITS_IN_BYTE) {
+            Some(size_bits) => Some(MemorySize { size_bits }),
+            None => None,
+        }
+    }
+}
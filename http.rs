@@ -0,0 +1,236 @@
+//! Parsing and formatting of HTTP headers that hold sizes and byte ranges
+//! 
+//! HTTP ranges list inclusive byte positions, which are converted to and from the half-open `MemoryRange`
+
+use crate::{fallible, MemoryRange, MemorySize};
+
+/// Error returned when an HTTP header value is malformed, or a range cannot be satisfied
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct InvalidHeaderError;
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for InvalidHeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid or unsatisfiable HTTP header value")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidHeaderError {}
+
+/// Parses a plain decimal number of bytes, rejecting signs and whitespace as HTTP requires
+fn parse_bytes(value: &str) -> Result<MemorySize, InvalidHeaderError> {
+    if value.is_empty() || !value.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(InvalidHeaderError);
+    }
+    let bytes: u64 = value.parse().map_err(|_| InvalidHeaderError)?;
+    bytes.checked_mul(8).map(MemorySize::from_bits).ok_or(InvalidHeaderError)
+}
+
+/// Returns the number of bytes in `size`, which HTTP headers cannot express fractions of
+fn whole_bytes(size: MemorySize) -> Result<u64, InvalidHeaderError> {
+    fallible::size_bytes(size).ok_or(InvalidHeaderError)
+}
+
+/// Converts `range` into the inclusive byte positions `(first, last)` used by HTTP
+fn inclusive_positions(range: MemoryRange) -> Result<(u64, u64), InvalidHeaderError> {
+    if range.is_empty() {
+        return Err(InvalidHeaderError);
+    }
+    Ok((whole_bytes(range.start())?, whole_bytes(range.end())? - 1))
+}
+
+/// Converts the inclusive byte positions `first..=last` into a range
+fn inclusive_range(first: MemorySize, last: MemorySize) -> Result<MemoryRange, InvalidHeaderError> {
+    if last < first {
+        return Err(InvalidHeaderError);
+    }
+    let end = last.size_bits().checked_add(8).ok_or(InvalidHeaderError)?;
+    Ok(MemoryRange::new(first, MemorySize::from_bits(end)))
+}
+
+/// Parses the value of a `Content-Length` header
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{http, MemorySize};
+///
+/// assert_eq!(http::parse_content_length("1024"), Ok(MemorySize::from_bytes(1024)));
+/// assert!(http::parse_content_length("-5").is_err());
+/// ```
+pub fn parse_content_length(value: &str) -> Result<MemorySize, InvalidHeaderError> {
+    parse_bytes(value.trim())
+}
+
+/// Formats `size` as the value of a `Content-Length` header
+/// 
+/// # Errors
+/// `InvalidHeaderError` if `size` is not a whole number of bytes
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{http, MemorySize};
+///
+/// assert_eq!(http::format_content_length(MemorySize::from_bytes(1024)), Ok("1024".to_string()));
+/// assert!(http::format_content_length(MemorySize::from_bits(12)).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn format_content_length(size: MemorySize) -> Result<String, InvalidHeaderError> {
+    whole_bytes(size).map(|bytes| bytes.to_string())
+}
+
+/// Parses the value of a `Range` header holding a single range, and resolves it against a resource of size `total`
+/// 
+/// Accepts `bytes=first-last`, `bytes=first-` and the suffix form `bytes=-length`.
+/// The end of the range is clamped to the end of the resource, and an error is returned if no part of the range is within it
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{http, MemoryRange, MemorySize};
+///
+/// let total = MemorySize::from_bytes(1000);
+/// let expected = MemoryRange::new(MemorySize::from_bytes(900), total);
+/// assert_eq!(http::parse_range("bytes=900-1999", total), Ok(expected));
+/// assert_eq!(http::parse_range("bytes=900-", total), Ok(expected));
+/// assert_eq!(http::parse_range("bytes=-100", total), Ok(expected));
+/// assert!(http::parse_range("bytes=1000-", total).is_err());
+/// ```
+pub fn parse_range(value: &str, total: MemorySize) -> Result<MemoryRange, InvalidHeaderError> {
+    let spec = value.trim().strip_prefix("bytes=").ok_or(InvalidHeaderError)?;
+    let (first, last) = spec.trim().split_once('-').ok_or(InvalidHeaderError)?;
+
+    if first.is_empty() {
+        //suffix range: the last `length` bytes
+        let length = parse_bytes(last)?;
+        if length == MemorySize::new() || total == MemorySize::new() {
+            return Err(InvalidHeaderError);
+        }
+        let start = if length < total {total - length} else {MemorySize::new()};
+        return Ok(MemoryRange::new(start, total));
+    }
+
+    let first = parse_bytes(first)?;
+    if first >= total {
+        return Err(InvalidHeaderError);
+    }
+    let range = if last.is_empty() {
+        MemoryRange::new(first, total)
+    } else {
+        inclusive_range(first, parse_bytes(last)?)?
+    };
+
+    Ok(MemoryRange::new(range.start(), range.end().min(total)))
+}
+
+/// Formats `range` as the value of a `Range` header requesting it, like `bytes=0-499`
+/// 
+/// # Errors
+/// `InvalidHeaderError` if `range` is empty or not made of whole bytes
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{http, MemoryRange, MemorySize};
+///
+/// let range = MemoryRange::new(MemorySize::from_bytes(900), MemorySize::from_bytes(1000));
+/// assert_eq!(http::format_range(range), Ok("bytes=900-999".to_string()));
+/// assert!(http::format_range(MemoryRange::new(MemorySize::new(), MemorySize::new())).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn format_range(range: MemoryRange) -> Result<String, InvalidHeaderError> {
+    let (first, last) = inclusive_positions(range)?;
+    Ok(format!("bytes={}-{}", first, last))
+}
+
+/// The value of a `Content-Range` header, describing which part of a resource a response holds
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{http::ContentRange, MemoryRange, MemorySize};
+///
+/// let header: ContentRange = "bytes 0-499/1234".parse().unwrap();
+/// assert_eq!(header.range(), MemoryRange::new(MemorySize::new(), MemorySize::from_bytes(500)));
+/// assert_eq!(header.total(), Some(MemorySize::from_bytes(1234)));
+/// assert_eq!(header.to_string(), "bytes 0-499/1234");
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct ContentRange {
+    range: MemoryRange,
+    total: Option<MemorySize>,
+}
+
+impl ContentRange {
+    /// Creates a header value saying that a response holds `range` of a resource of size `total`, if known
+    /// 
+    /// # Errors
+    /// `InvalidHeaderError` if `range` is empty, either size is not a whole number of bytes, or `range` ends after `total`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{http::ContentRange, MemoryRange, MemorySize};
+    ///
+    /// let range = MemoryRange::new(MemorySize::new(), MemorySize::from_bytes(500));
+    /// let header = ContentRange::new(range, None).unwrap();
+    /// assert_eq!(header.to_string(), "bytes 0-499/*");
+    /// assert!(ContentRange::new(range, Some(MemorySize::from_bytes(100))).is_err());
+    /// ```
+    pub fn new(range: MemoryRange, total: Option<MemorySize>) -> Result<ContentRange, InvalidHeaderError> {
+        inclusive_positions(range)?;
+        if let Some(total) = total {
+            whole_bytes(total)?;
+            if range.end() > total {
+                return Err(InvalidHeaderError);
+            }
+        }
+        Ok(ContentRange { range, total })
+    }
+
+    /// Returns the part of the resource in the response, which is a non-empty whole number of bytes
+    pub const fn range(&self) -> MemoryRange {
+        self.range
+    }
+
+    /// Returns the size of the whole resource, if known
+    pub const fn total(&self) -> Option<MemorySize> {
+        self.total
+    }
+}
+
+impl core::str::FromStr for ContentRange {
+    type Err = InvalidHeaderError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let spec = value.trim().strip_prefix("bytes ").ok_or(InvalidHeaderError)?;
+        let (positions, total) = spec.split_once('/').ok_or(InvalidHeaderError)?;
+        let (first, last) = positions.split_once('-').ok_or(InvalidHeaderError)?;
+
+        let range = inclusive_range(parse_bytes(first)?, parse_bytes(last)?)?;
+        let total = match total {
+            "*" => None,
+            total => Some(parse_bytes(total)?),
+        };
+
+        ContentRange::new(range, total)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for ContentRange {
+    /// Formats the header value, like `bytes 0-499/1234`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        //`ContentRange::new` has checked that the range is non-empty whole bytes
+        let first = self.range.start().size_bytes();
+        let last = self.range.end().size_bytes() - 1;
+
+        write!(f, "bytes {}-{}/", first, last)?;
+        match self.total {
+            Some(total) => write!(f, "{}", total.size_bytes()),
+            None => write!(f, "*"),
+        }
+    }
+}
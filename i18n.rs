@@ -0,0 +1,186 @@
+//! Localized unit names for human-readable formatting, chosen with `FormatOptions::locale`
+
+use crate::format::{Unit, UnitSystem, BIT_UNIT, IEC_BIT_UNITS, IEC_UNITS, SI_BIT_UNITS, SI_UNITS};
+
+/// The unit names and decimal separator that sizes are formatted with in a language
+///
+/// Other languages are supported by filling in the tables, each starting from a single byte or bit.
+/// Only a singular and a single plural form of the full names are supported
+///
+/// # Examples
+///
+/// ```
+/// use memory_size::format::{FormatOptions, Unit};
+/// use memory_size::i18n::Locale;
+/// use memory_size::MemorySize;
+///
+/// static SWISS_GERMAN: Locale = Locale { decimal_separator: '.', ..Locale::GERMAN };
+///
+/// let options = FormatOptions::new().locale(&SWISS_GERMAN);
+/// assert_eq!(MemorySize::from_bytes(1536).display_with(options).to_string(), "1.50 KiB");
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct Locale {
+    /// Binary byte units, like `KiB`
+    pub iec: [Unit; 7],
+    /// Decimal byte units, like `kB`
+    pub si: [Unit; 7],
+    /// Binary bit units, like `Kibit`
+    pub iec_bits: [Unit; 7],
+    /// Decimal bit units, like `kbit`
+    pub si_bits: [Unit; 7],
+    /// The character between the integer and fractional parts of numbers
+    pub decimal_separator: char,
+}
+
+impl Locale {
+    /// English unit names, which are used when no locale is chosen
+    pub const ENGLISH: Locale = Locale {
+        iec: IEC_UNITS,
+        si: SI_UNITS,
+        iec_bits: IEC_BIT_UNITS,
+        si_bits: SI_BIT_UNITS,
+        decimal_separator: '.',
+    };
+
+    /// French unit names, counting in octets like `Kio` and `Mo`
+    pub const FRENCH: Locale = Locale {
+        iec: [
+            Unit::new("o", "octet", "octets"),
+            Unit::new("Kio", "kibioctet", "kibioctets"),
+            Unit::new("Mio", "mébioctet", "mébioctets"),
+            Unit::new("Gio", "gibioctet", "gibioctets"),
+            Unit::new("Tio", "tébioctet", "tébioctets"),
+            Unit::new("Pio", "pébioctet", "pébioctets"),
+            Unit::new("Eio", "exbioctet", "exbioctets"),
+        ],
+        si: [
+            Unit::new("o", "octet", "octets"),
+            Unit::new("ko", "kilooctet", "kilooctets"),
+            Unit::new("Mo", "mégaoctet", "mégaoctets"),
+            Unit::new("Go", "gigaoctet", "gigaoctets"),
+            Unit::new("To", "téraoctet", "téraoctets"),
+            Unit::new("Po", "pétaoctet", "pétaoctets"),
+            Unit::new("Eo", "exaoctet", "exaoctets"),
+        ],
+        iec_bits: [
+            BIT_UNIT,
+            Unit::new("Kibit", "kibibit", "kibibits"),
+            Unit::new("Mibit", "mébibit", "mébibits"),
+            Unit::new("Gibit", "gibibit", "gibibits"),
+            Unit::new("Tibit", "tébibit", "tébibits"),
+            Unit::new("Pibit", "pébibit", "pébibits"),
+            Unit::new("Eibit", "exbibit", "exbibits"),
+        ],
+        si_bits: [
+            BIT_UNIT,
+            Unit::new("kbit", "kilobit", "kilobits"),
+            Unit::new("Mbit", "mégabit", "mégabits"),
+            Unit::new("Gbit", "gigabit", "gigabits"),
+            Unit::new("Tbit", "térabit", "térabits"),
+            Unit::new("Pbit", "pétabit", "pétabits"),
+            Unit::new("Ebit", "exabit", "exabits"),
+        ],
+        decimal_separator: ',',
+    };
+
+    /// German unit names, which share the English abbreviations
+    pub const GERMAN: Locale = Locale {
+        iec: [
+            Unit::new("B", "Byte", "Byte"),
+            Unit::new("KiB", "Kibibyte", "Kibibyte"),
+            Unit::new("MiB", "Mebibyte", "Mebibyte"),
+            Unit::new("GiB", "Gibibyte", "Gibibyte"),
+            Unit::new("TiB", "Tebibyte", "Tebibyte"),
+            Unit::new("PiB", "Pebibyte", "Pebibyte"),
+            Unit::new("EiB", "Exbibyte", "Exbibyte"),
+        ],
+        si: [
+            Unit::new("B", "Byte", "Byte"),
+            Unit::new("kB", "Kilobyte", "Kilobyte"),
+            Unit::new("MB", "Megabyte", "Megabyte"),
+            Unit::new("GB", "Gigabyte", "Gigabyte"),
+            Unit::new("TB", "Terabyte", "Terabyte"),
+            Unit::new("PB", "Petabyte", "Petabyte"),
+            Unit::new("EB", "Exabyte", "Exabyte"),
+        ],
+        iec_bits: [
+            Unit::new("bit", "Bit", "Bit"),
+            Unit::new("Kibit", "Kibibit", "Kibibit"),
+            Unit::new("Mibit", "Mebibit", "Mebibit"),
+            Unit::new("Gibit", "Gibibit", "Gibibit"),
+            Unit::new("Tibit", "Tebibit", "Tebibit"),
+            Unit::new("Pibit", "Pebibit", "Pebibit"),
+            Unit::new("Eibit", "Exbibit", "Exbibit"),
+        ],
+        si_bits: [
+            Unit::new("bit", "Bit", "Bit"),
+            Unit::new("kbit", "Kilobit", "Kilobit"),
+            Unit::new("Mbit", "Megabit", "Megabit"),
+            Unit::new("Gbit", "Gigabit", "Gigabit"),
+            Unit::new("Tbit", "Terabit", "Terabit"),
+            Unit::new("Pbit", "Petabit", "Petabit"),
+            Unit::new("Ebit", "Exabit", "Exabit"),
+        ],
+        decimal_separator: ',',
+    };
+
+    /// Russian unit names, like `КиБ` and `Мбит`
+    ///
+    /// The plural full names are the genitive singular used after fractional numbers, like `1,50 кибибайта`
+    pub const RUSSIAN: Locale = Locale {
+        iec: [
+            Unit::new("Б", "байт", "байта"),
+            Unit::new("КиБ", "кибибайт", "кибибайта"),
+            Unit::new("МиБ", "мебибайт", "мебибайта"),
+            Unit::new("ГиБ", "гибибайт", "гибибайта"),
+            Unit::new("ТиБ", "тебибайт", "тебибайта"),
+            Unit::new("ПиБ", "пебибайт", "пебибайта"),
+            Unit::new("ЭиБ", "эксбибайт", "эксбибайта"),
+        ],
+        si: [
+            Unit::new("Б", "байт", "байта"),
+            Unit::new("кБ", "килобайт", "килобайта"),
+            Unit::new("МБ", "мегабайт", "мегабайта"),
+            Unit::new("ГБ", "гигабайт", "гигабайта"),
+            Unit::new("ТБ", "терабайт", "терабайта"),
+            Unit::new("ПБ", "петабайт", "петабайта"),
+            Unit::new("ЭБ", "эксабайт", "эксабайта"),
+        ],
+        iec_bits: [
+            Unit::new("бит", "бит", "бита"),
+            Unit::new("Кибит", "кибибит", "кибибита"),
+            Unit::new("Мибит", "мебибит", "мебибита"),
+            Unit::new("Гибит", "гибибит", "гибибита"),
+            Unit::new("Тибит", "тебибит", "тебибита"),
+            Unit::new("Пибит", "пебибит", "пебибита"),
+            Unit::new("Эибит", "эксбибит", "эксбибита"),
+        ],
+        si_bits: [
+            Unit::new("бит", "бит", "бита"),
+            Unit::new("кбит", "килобит", "килобита"),
+            Unit::new("Мбит", "мегабит", "мегабита"),
+            Unit::new("Гбит", "гигабит", "гигабита"),
+            Unit::new("Тбит", "терабит", "терабита"),
+            Unit::new("Пбит", "петабит", "петабита"),
+            Unit::new("Эбит", "эксабит", "эксабита"),
+        ],
+        decimal_separator: ',',
+    };
+
+    /// Returns the units of `system`, starting from a byte, or from a bit if `bits` is set
+    pub(crate) const fn units(&self, system: UnitSystem, bits: bool) -> &[Unit; 7] {
+        match (system, bits) {
+            (UnitSystem::Iec, false) => &self.iec,
+            (UnitSystem::Si, false) => &self.si,
+            (UnitSystem::Iec, true) => &self.iec_bits,
+            (UnitSystem::Si, true) => &self.si_bits,
+        }
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::ENGLISH
+    }
+}
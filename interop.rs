@@ -0,0 +1,120 @@
+//! Integrations with other crates, each behind a feature of the same name
+
+#[cfg(feature = "criterion")]
+impl From<crate::MemorySize> for criterion::Throughput {
+    /// Converts to a throughput in bytes, or in bits if the size is not a whole number of bytes
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use criterion::Throughput;
+    /// use memory_size::MemorySize;
+    ///
+    /// assert!(matches!(Throughput::from(MemorySize::from_bytes(4096)), Throughput::Bytes(4096)));
+    /// assert!(matches!(Throughput::from(MemorySize::from_bits(12)), Throughput::Bits(12)));
+    /// ```
+    fn from(value: crate::MemorySize) -> Self {
+        match value.size_bits_bytes() {
+            (0, bytes) => criterion::Throughput::Bytes(bytes),
+            _ => criterion::Throughput::Bits(value.size_bits()),
+        }
+    }
+}
+
+// SAFETY: `MemorySize` is `repr(transparent)` over a `u64`, which is `Zeroable`, and zero bits is a valid size
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for crate::MemorySize {}
+
+// SAFETY: `MemorySize` is `repr(transparent)` over a `u64`, which is `Pod`, and every `u64` is a valid number of bits
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for crate::MemorySize {}
+
+/// Serialized as a number of bytes
+/// 
+/// Serializing fails if the size is not a whole number of bytes
+#[cfg(feature = "serde")]
+impl serde::Serialize for crate::MemorySize {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.size_bits_bytes() {
+            (0, bytes) => serializer.serialize_u64(bytes),
+            _ => Err(serde::ser::Error::custom("size is not a whole number of bytes")),
+        }
+    }
+}
+
+/// Deserializes from a number of bytes
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for crate::MemorySize {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = u64::deserialize(deserializer)?;
+        bytes.checked_mul(8)
+            .map(crate::MemorySize::from_bits)
+            .ok_or_else(|| serde::de::Error::custom("size is too large"))
+    }
+}
+
+#[cfg(feature = "num-rational")]
+impl crate::MemorySize {
+    /// Scales `&self` by `ratio` exactly, rounding the result to a whole number of bits with `rounding`
+    /// 
+    /// # Panics
+    /// if the denominator of `ratio` is zero, or the result does not fit in a `MemorySize`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, RoundingMode};
+    /// use num_rational::Ratio;
+    ///
+    /// let quota = MemorySize::from_bits(100);
+    /// assert_eq!(quota.mul_ratio(Ratio::new(2, 3), RoundingMode::Floor), MemorySize::from_bits(66));
+    /// assert_eq!(quota.mul_ratio(Ratio::new(2, 3), RoundingMode::Nearest), MemorySize::from_bits(67));
+    /// assert_eq!(quota.mul_ratio(Ratio::new(3, 2), RoundingMode::Ceil), MemorySize::from_bits(150));
+    /// ```
+    pub fn mul_ratio(&self, ratio: num_rational::Ratio<u64>, rounding: crate::RoundingMode) -> crate::MemorySize {
+        self.scale(*ratio.numer(), *ratio.denom(), rounding)
+    }
+}
+
+/// Parses arguments with `MemorySize::from_str`, then checks the constraints of the parser
+/// 
+/// # Examples
+///
+/// ```
+/// use clap::{Arg, Command};
+/// use memory_size::MemorySize;
+///
+/// let cmd = Command::new("app").arg(
+///     Arg::new("max-memory")
+///         .long("max-memory")
+///         .value_parser(MemorySize::arg_parser().min(MemorySize::from_bytes(1 << 20))),
+/// );
+///
+/// let matches = cmd.clone().try_get_matches_from(["app", "--max-memory", "2GiB"]).unwrap();
+/// assert_eq!(matches.get_one::<MemorySize>("max-memory"), Some(&MemorySize::from_bytes(2 << 30)));
+///
+/// assert!(cmd.try_get_matches_from(["app", "--max-memory", "1 KiB"]).is_err());
+/// ```
+#[cfg(feature = "clap")]
+impl clap::builder::TypedValueParser for crate::SizeArgParser {
+    type Value = crate::MemorySize;
+
+    fn parse_ref(&self, cmd: &clap::Command, arg: Option<&clap::Arg>, value: &std::ffi::OsStr) -> Result<Self::Value, clap::Error> {
+        let arg = arg.map_or_else(|| "...".to_string(), |arg| arg.to_string());
+        let value = value.to_str()
+            .ok_or_else(|| cmd.clone().error(clap::error::ErrorKind::InvalidUtf8, format!("invalid UTF-8 in the value for '{}'", arg)))?;
+
+        self.parse(value)
+            .map_err(|e| cmd.clone().error(clap::error::ErrorKind::ValueValidation, format!("invalid value '{}' for '{}': {}", value, arg, e)))
+    }
+}
+
+/// Lets `MemorySize` arguments be parsed by clap without naming a value parser, accepting any size
+#[cfg(feature = "clap")]
+impl clap::builder::ValueParserFactory for crate::MemorySize {
+    type Parser = crate::SizeArgParser;
+
+    fn value_parser() -> Self::Parser {
+        crate::MemorySize::arg_parser()
+    }
+}
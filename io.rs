@@ -0,0 +1,323 @@
+//! Adapters for expressing IO limits as `MemorySize`
+
+use crate::{ByteRate, MemorySize};
+use std::io::{self, Read, Take, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+//largest buffer `copy_with_progress` allocates, however large the chunks are
+const COPY_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Extends `Read` with size-limited adapters
+pub trait ReadSizedExt: Read + Sized {
+    /// Creates an adapter that reads at most `limit` from this reader, like `Read::take`
+    /// 
+    /// Limits that are not a whole number of bytes are rounded down
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{io::ReadSizedExt, MemorySize};
+    /// use std::io::Read;
+    ///
+    /// let data = [0u8; 100];
+    /// let mut body = Vec::new();
+    /// data.as_slice().take_sized(MemorySize::from_bytes(10)).read_to_end(&mut body).unwrap();
+    /// assert_eq!(body.len(), 10);
+    /// ```
+    fn take_sized(self, limit: MemorySize) -> Take<Self> {
+        self.take(limit.size_bits_bytes().1)
+    }
+
+    /// Reads exactly `size` from this reader into a newly allocated buffer
+    /// 
+    /// `size` often comes from an untrusted length field, so it is checked against `cap` before anything is allocated
+    /// 
+    /// # Errors
+    /// - `InvalidInput` if `size` is not a whole number of bytes
+    /// - `InvalidData` if `size` is larger than `cap`
+    /// - `UnexpectedEof` if the reader ends early, or any error from the reader
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{io::ReadSizedExt, MemorySize};
+    ///
+    /// let mut data: &[u8] = &[1, 2, 3, 4, 5];
+    /// let cap = MemorySize::from_bytes(1024);
+    /// let buffer = data.read_exact_sized(MemorySize::from_bytes(3), cap).unwrap();
+    /// assert_eq!(buffer, [1, 2, 3]);
+    ///
+    /// assert!(data.read_exact_sized(MemorySize::from_bytes(1 << 40), cap).is_err());
+    /// ```
+    fn read_exact_sized(&mut self, size: MemorySize, cap: MemorySize) -> io::Result<Vec<u8>> {
+        let (bits, bytes) = size.size_bits_bytes();
+        if bits != 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "size is not a whole number of bytes"));
+        }
+        if size > cap {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "size is larger than the allowed maximum"));
+        }
+        let len = usize::try_from(bytes)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "size does not fit in memory"))?;
+
+        let mut buffer = vec![0; len];
+        self.read_exact(&mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+impl<R: Read> ReadSizedExt for R {}
+
+/// Wraps a reader or writer, limiting its throughput to a `ByteRate`
+/// 
+/// This uses a token bucket holding up to one second of transfer, so short bursts can briefly exceed the rate.
+/// Calls block the current thread until the rate allows more data through
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{io::Throttle, ByteRate, MemorySize};
+/// use std::io::Write;
+///
+/// let rate = ByteRate::per_second(MemorySize::from_bytes(1024 * 1024));
+/// let mut output = Throttle::new(Vec::new(), rate);
+/// output.write_all(b"hello").unwrap();
+/// assert_eq!(output.into_inner(), b"hello");
+/// ```
+#[derive(Debug)]
+pub struct Throttle<T> {
+    inner: T,
+    bucket: TokenBucket,
+}
+
+impl<T> Throttle<T> {
+    /// Creates a throttle that limits `inner` to `rate`
+    /// 
+    /// # Panics
+    /// if `rate` is less than one byte per second
+    pub fn new(inner: T, rate: ByteRate) -> Throttle<T> {
+        Throttle { inner, bucket: TokenBucket::new(rate) }
+    }
+
+    /// Returns a reference to the wrapped reader or writer
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwraps the throttle, returning the wrapped reader or writer
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Waits until at least one byte can be transferred, then takes permission to transfer up to `wanted` bytes
+    fn acquire(&mut self, wanted: usize) -> usize {
+        loop {
+            match self.bucket.take(wanted) {
+                Ok(granted) => return granted,
+                Err(wait) => thread::sleep(wait),
+            }
+        }
+    }
+}
+
+/// Token bucket holding up to one second of transfer, used to implement throttling
+#[derive(Debug)]
+pub(crate) struct TokenBucket {
+    bytes_per_second: f64,
+    /// Number of bytes that can be transferred right now
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// # Panics
+    /// if `rate` is less than one byte per second
+    pub(crate) fn new(rate: ByteRate) -> TokenBucket {
+        let bytes_per_second = rate.size_per_second().size_bits_bytes().1 as f64;
+        assert!(bytes_per_second >= 1.0, "rate must be at least one byte per second");
+
+        TokenBucket {
+            bytes_per_second,
+            tokens: bytes_per_second,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Takes permission to transfer up to `wanted` bytes
+    /// 
+    /// If not even one byte can be transferred, returns how long to wait before trying again
+    pub(crate) fn take(&mut self, wanted: usize) -> Result<usize, Duration> {
+        if wanted == 0 {
+            return Ok(0);
+        }
+        let now = Instant::now();
+        let refill = now.duration_since(self.last_refill).as_secs_f64() * self.bytes_per_second;
+        self.tokens = (self.tokens + refill).min(self.bytes_per_second);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            let granted = wanted.min(self.tokens as usize);
+            self.tokens -= granted as f64;
+            Ok(granted)
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / self.bytes_per_second))
+        }
+    }
+
+    /// Returns permission for bytes that were not transferred
+    pub(crate) fn refund(&mut self, unused: usize) {
+        self.tokens += unused as f64;
+    }
+}
+
+impl<R: Read> Read for Throttle<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let granted = self.acquire(buf.len());
+        let result = self.inner.read(&mut buf[..granted]);
+        self.bucket.refund(granted - *result.as_ref().unwrap_or(&0));
+        result
+    }
+}
+
+impl<W: Write> Write for Throttle<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let granted = self.acquire(buf.len());
+        let result = self.inner.write(&buf[..granted]);
+        self.bucket.refund(granted - *result.as_ref().unwrap_or(&0));
+        result
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Wraps a reader or writer, counting the amount of data transferred through it
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{io::Counting, MemorySize};
+/// use std::io::Write;
+///
+/// let mut output = Counting::new(Vec::new());
+/// output.write_all(b"hello").unwrap();
+/// assert_eq!(output.transferred(), MemorySize::from_bytes(5));
+/// ```
+#[derive(Debug)]
+pub struct Counting<T> {
+    inner: T,
+    transferred: MemorySize,
+}
+
+impl<T> Counting<T> {
+    /// Wraps `inner`, starting the count at zero
+    pub fn new(inner: T) -> Counting<T> {
+        Counting { inner, transferred: MemorySize::new() }
+    }
+
+    /// Returns the amount of data read or written so far
+    pub fn transferred(&self) -> MemorySize {
+        self.transferred
+    }
+
+    /// Returns a reference to the wrapped reader or writer
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwraps the counter, returning the wrapped reader or writer
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for Counting<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.transferred += MemorySize::from_bytes(read as u64);
+        Ok(read)
+    }
+}
+
+impl<W: Write> Write for Counting<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.transferred += MemorySize::from_bytes(written as u64);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Copies everything from `reader` to `writer` in chunks of `chunk_size`, calling `progress` after each chunk
+/// 
+/// `progress` receives the total amount copied so far, and the average rate since the copy started.
+/// The final chunk is reported even if it is shorter than `chunk_size`.
+/// The copy goes through a buffer of at most 64 KiB, so large chunks do not need to fit in memory.
+/// Returns the total amount copied
+/// 
+/// # Panics
+/// if `chunk_size` is less than one byte
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{io::copy_with_progress, MemorySize};
+///
+/// let data = [0u8; 10_000];
+/// let mut output = Vec::new();
+/// let mut updates = Vec::new();
+///
+/// let copied = copy_with_progress(&mut data.as_slice(), &mut output, MemorySize::from_bytes(4096), |copied, _rate| {
+///     updates.push(copied.size_bytes());
+/// }).unwrap();
+///
+/// assert_eq!(copied, MemorySize::from_bytes(10_000));
+/// assert_eq!(updates, [4096, 8192, 10_000]);
+/// ```
+pub fn copy_with_progress<R, W, F>(reader: &mut R, writer: &mut W, chunk_size: MemorySize, mut progress: F) -> io::Result<MemorySize>
+where
+    R: Read + ?Sized,
+    W: Write + ?Sized,
+    F: FnMut(MemorySize, ByteRate),
+{
+    let chunk_bytes = chunk_size.size_bits_bytes().1;
+    assert!(chunk_bytes != 0, "chunk size must be at least one byte");
+
+    let mut buffer = vec![0; usize::try_from(chunk_bytes).map_or(COPY_BUFFER_SIZE, |bytes| bytes.min(COPY_BUFFER_SIZE))];
+    let mut reader = Counting::new(reader);
+    let start = Instant::now();
+    let mut report = |copied: MemorySize| {
+        //avoid measuring a rate over an unrealistically short time
+        let elapsed = start.elapsed().max(Duration::from_millis(1));
+        progress(copied, ByteRate::from_size_and_duration(copied, elapsed));
+    };
+
+    //bytes copied since the last report
+    let mut unreported = 0;
+    loop {
+        let wanted = usize::try_from(chunk_bytes - unreported).map_or(buffer.len(), |left| left.min(buffer.len()));
+        let read = match reader.read(&mut buffer[..wanted]) {
+            Ok(0) => {
+                if unreported != 0 {
+                    report(reader.transferred());
+                }
+                return Ok(reader.transferred());
+            }
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        writer.write_all(&buffer[..read])?;
+
+        unreported += read as u64;
+        if unreported == chunk_bytes {
+            report(reader.transferred());
+            unreported = 0;
+        }
+    }
+}
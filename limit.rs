@@ -0,0 +1,232 @@
+use crate::MemorySize;
+use core::cmp::Ordering;
+use core::ops::Range;
+use core::str::FromStr;
+
+/// A limit on memory usage, which may be unlimited
+/// 
+/// Any size is below `Unlimited`, so limits can be compared with each other and with sizes directly
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{MemoryLimit, MemorySize};
+///
+/// let limit: MemoryLimit = "max".parse().unwrap();
+/// assert_eq!(limit, MemoryLimit::Unlimited);
+/// assert!(MemorySize::from_bytes(1 << 40) < limit);
+///
+/// let limit: MemoryLimit = "4096".parse().unwrap();
+/// assert!(limit.allows(MemorySize::from_bytes(4096)));
+/// assert!(!limit.allows(MemorySize::from_bytes(4097)));
+/// ```
+#[derive(PartialEq, PartialOrd, Ord, Eq, Clone, Copy, Hash, Debug, Default)]
+pub enum MemoryLimit {
+    /// At most this much memory may be used
+    Limited(MemorySize),
+    /// There is no limit
+    #[default]
+    Unlimited,
+}
+
+impl MemoryLimit {
+    /// Checks whether `size` is within the limit
+    pub const fn allows(&self, size: MemorySize) -> bool {
+        match self {
+            MemoryLimit::Limited(limit) => size.size_bits() <= limit.size_bits(),
+            MemoryLimit::Unlimited => true,
+        }
+    }
+
+    /// Returns the limit as a size, or `None` if unlimited
+    pub const fn as_size(&self) -> Option<MemorySize> {
+        match self {
+            MemoryLimit::Limited(limit) => Some(*limit),
+            MemoryLimit::Unlimited => None,
+        }
+    }
+}
+
+impl From<MemorySize> for MemoryLimit {
+    fn from(value: MemorySize) -> Self {
+        MemoryLimit::Limited(value)
+    }
+}
+
+impl PartialEq<MemorySize> for MemoryLimit {
+    fn eq(&self, other: &MemorySize) -> bool {
+        *self == MemoryLimit::Limited(*other)
+    }
+}
+
+impl PartialEq<MemoryLimit> for MemorySize {
+    fn eq(&self, other: &MemoryLimit) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<MemorySize> for MemoryLimit {
+    fn partial_cmp(&self, other: &MemorySize) -> Option<Ordering> {
+        Some(self.cmp(&MemoryLimit::Limited(*other)))
+    }
+}
+
+impl PartialOrd<MemoryLimit> for MemorySize {
+    fn partial_cmp(&self, other: &MemoryLimit) -> Option<Ordering> {
+        Some(MemoryLimit::Limited(*self).cmp(other))
+    }
+}
+
+/// Error returned when parsing a `MemoryLimit` fails
+/// 
+/// Records where the invalid text is in the input, and the keyword the user most likely meant
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::MemoryLimit;
+///
+/// let err = "  unlimted".parse::<MemoryLimit>().unwrap_err();
+/// assert_eq!(err.span(), 2..10);
+/// assert_eq!(err.suggestion(), Some("unlimited"));
+/// assert_eq!(err.to_string(), "invalid memory limit at bytes 2..10, did you mean \"unlimited\"?");
+/// ```
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+pub struct ParseLimitError {
+    span: Range<usize>,
+    suggestion: Option<&'static str>,
+}
+
+impl ParseLimitError {
+    /// Returns the byte range of the invalid text within the input
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns the keyword that the input was probably a misspelling of, if any
+    pub fn suggestion(&self) -> Option<&'static str> {
+        self.suggestion
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for ParseLimitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid memory limit at bytes {:?}", self.span)?;
+        match self.suggestion {
+            Some(keyword) => write!(f, ", did you mean \"{}\"?", keyword),
+            None => write!(f, ", expected a size or one of \"unlimited\", \"max\" or \"none\""),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseLimitError {}
+
+/// Words that mean there is no limit
+const UNLIMITED_KEYWORDS: [&str; 3] = ["unlimited", "max", "none"];
+
+/// Finds the keyword within two edits of `word`, ignoring case
+fn suggest_keyword(word: &str) -> Option<&'static str> {
+    UNLIMITED_KEYWORDS.into_iter()
+        .map(|keyword| (keyword, edit_distance(word, keyword)))
+        .filter(|&(_, distance)| distance <= 2)
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(keyword, _)| keyword)
+}
+
+/// Calculates the Levenshtein distance between `word` and a short `keyword`, ignoring case
+pub(crate) fn edit_distance(word: &str, keyword: &str) -> usize {
+    const MAX_KEYWORD: usize = 16;
+    let keyword = keyword.as_bytes();
+    assert!(keyword.len() < MAX_KEYWORD);
+
+    //distances from the part of `word` processed so far to each prefix of `keyword`
+    let mut row = [0; MAX_KEYWORD];
+    for (i, cell) in row.iter_mut().enumerate().take(keyword.len() + 1) {
+        *cell = i;
+    }
+
+    for (i, c) in word.bytes().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for j in 1..=keyword.len() {
+            let substitution = diagonal + usize::from(!c.eq_ignore_ascii_case(&keyword[j - 1]));
+            diagonal = row[j];
+            row[j] = substitution.min(row[j] + 1).min(row[j - 1] + 1);
+        }
+    }
+
+    row[keyword.len()]
+}
+
+impl FromStr for MemoryLimit {
+    type Err = ParseLimitError;
+
+    /// Parses `unlimited`, `max` or `none` in any case, or any size that `MemorySize::from_str` accepts,
+    /// including a bare number of bytes as used by cgroup limit files
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let start = s.len() - s.trim_start().len();
+        let trimmed = s.trim();
+        if UNLIMITED_KEYWORDS.iter().any(|keyword| keyword.eq_ignore_ascii_case(trimmed)) {
+            return Ok(MemoryLimit::Unlimited);
+        }
+
+        let error = || ParseLimitError {
+            span: start..start + trimmed.len(),
+            //numbers with typos are not misspelt keywords
+            suggestion: if trimmed.bytes().all(|c| c.is_ascii_alphabetic()) {suggest_keyword(trimmed)} else {None},
+        };
+        trimmed.parse().map(MemoryLimit::Limited).map_err(|_| error())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for MemoryLimit {
+    /// Formats the limit exactly with `MemorySize::display_round_trip`, or as `unlimited`, so that parsing the output gives back the same limit
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemoryLimit::Limited(limit) => write!(f, "{}", limit.display_round_trip()),
+            MemoryLimit::Unlimited => write!(f, "unlimited"),
+        }
+    }
+}
+
+/// Serialized as the string `"unlimited"`, or the same way as a `MemorySize`.
+/// Deserializes from a number of bytes, or any string that `from_str` accepts
+#[cfg(feature = "serde")]
+impl serde::Serialize for MemoryLimit {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            MemoryLimit::Limited(limit) => limit.serialize(serializer),
+            MemoryLimit::Unlimited => serializer.serialize_str("unlimited"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MemoryLimit {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct LimitVisitor;
+
+        impl serde::de::Visitor<'_> for LimitVisitor {
+            type Value = MemoryLimit;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, "a number of bytes, or \"unlimited\"")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, bytes: u64) -> Result<MemoryLimit, E> {
+                bytes.checked_mul(8)
+                    .map(|bits| MemoryLimit::Limited(MemorySize::from_bits(bits)))
+                    .ok_or_else(|| E::custom("memory limit is too large"))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<MemoryLimit, E> {
+                s.parse().map_err(|_| E::invalid_value(serde::de::Unexpected::Str(s), &self))
+            }
+        }
+
+        deserializer.deserialize_any(LimitVisitor)
+    }
+}
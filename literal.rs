@@ -0,0 +1,195 @@
+use crate::{parse::PREFIXES, MemorySize, BITS_IN_BYTE};
+
+/// Compares two byte strings in a const context
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Checks whether `unit` is `b`, or `bit` or `bits` in any case, as `from_str` accepts
+const fn is_bit_unit(unit: &[u8]) -> bool {
+    bytes_eq(unit, b"b") || unit.eq_ignore_ascii_case(b"bit") || unit.eq_ignore_ascii_case(b"bits")
+}
+
+/// Finds the bits in one of an abbreviated `unit`, and the multiple of bits that fractions round to
+const fn unit_bits(unit: &[u8]) -> Option<(u128, u128)> {
+    let byte = BITS_IN_BYTE as u128;
+    if unit.is_empty() || bytes_eq(unit, b"B") || unit.eq_ignore_ascii_case(b"byte") || unit.eq_ignore_ascii_case(b"bytes") {
+        return Some((byte, byte));
+    }
+    if is_bit_unit(unit) {
+        return Some((1, 1));
+    }
+
+    let mut power = 0;
+    while power < PREFIXES.len() {
+        if unit[0].eq_ignore_ascii_case(&(PREFIXES[power].0 as u8)) {
+            let (binary, rest) = match unit.split_first() {
+                Some((_, [b'i' | b'I', rest @ ..])) => (true, rest),
+                Some((_, rest)) => (false, rest),
+                None => return None,
+            };
+            let exponent = PREFIXES[power].3;
+            let size = if binary {1u128 << (10 * exponent)} else {1000u128.pow(exponent as u32)};
+
+            if bytes_eq(rest, b"B") {
+                return Some((size * byte, byte));
+            }
+            if is_bit_unit(rest) {
+                return Some((size, 1));
+            }
+            return None;
+        }
+        power += 1;
+    }
+    None
+}
+
+impl MemorySize {
+    /// Parses a size in a const context, returning `None` if `s` is invalid
+    /// 
+    /// This accepts a single decimal, hexadecimal or binary number, optionally followed by an abbreviated unit like `KiB`, `MB` or `Mbit`,
+    /// and gives the same result as `from_str`. Units written out in full, like `kilobytes`, and sizes made of several terms, like `1 GiB 512 MiB`,
+    /// are not supported and give `None`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// const BUFFER: MemorySize = MemorySize::parse_const("1.5 KiB").unwrap();
+    /// assert_eq!(BUFFER, MemorySize::from_bytes(1536));
+    /// assert!(MemorySize::parse_const("4 kilobytes").is_none());
+    /// ```
+    pub const fn parse_const(s: &str) -> Option<MemorySize> {
+        let s = s.trim_ascii().as_bytes();
+        //a single term can start with the `+` that `from_str` allows between terms
+        let s = match s {
+            [b'+', rest @ ..] => rest.trim_ascii_start(),
+            _ => s,
+        };
+
+        let mut mantissa: u128 = 0;
+        let mut scale: Option<u32> = None;
+        let mut digits = 0;
+        let mut i = 0;
+
+        let radix = match s {
+            [b'0', b'x' | b'X', digit, ..] if digit.is_ascii_hexdigit() => 16,
+            [b'0', b'b' | b'B', b'0' | b'1', ..] => 2,
+            _ => 10,
+        };
+        if radix != 10 {
+            i = 2;
+            while i < s.len() {
+                let digit = match s[i] {
+                    b'0'..=b'9' => s[i] - b'0',
+                    b'a'..=b'f' => s[i] - b'a' + 10,
+                    b'A'..=b'F' => s[i] - b'A' + 10,
+                    _ => break,
+                };
+                if digit as u128 >= radix {
+                    break;
+                }
+                mantissa = match mantissa.checked_mul(radix) {
+                    Some(shifted) => match shifted.checked_add(digit as u128) {
+                        Some(mantissa) => mantissa,
+                        None => return None,
+                    },
+                    None => return None,
+                };
+                i += 1;
+            }
+            //the prefix is only matched when followed by a digit
+            digits = 1;
+        }
+
+        while radix == 10 && i < s.len() {
+            match s[i] {
+                b'0'..=b'9' => {
+                    mantissa = match mantissa.checked_mul(10) {
+                        Some(shifted) => match shifted.checked_add((s[i] - b'0') as u128) {
+                            Some(mantissa) => mantissa,
+                            None => return None,
+                        },
+                        None => return None,
+                    };
+                    if let Some(scale) = &mut scale {
+                        *scale += 1;
+                    }
+                    digits += 1;
+                }
+                b'.' if scale.is_none() => scale = Some(0),
+                _ => break,
+            }
+            i += 1;
+        }
+        let scale = match scale {
+            Some(scale) => scale,
+            None => 0,
+        };
+        if digits == 0 || scale > 19 {
+            return None;
+        }
+
+        let (_, unit) = s.split_at(i);
+        let Some((unit_bits, rounding)) = unit_bits(unit.trim_ascii_start()) else {return None};
+
+        let Some(bits) = mantissa.checked_mul(unit_bits) else {return None};
+        let divisor = 10u128.pow(scale);
+        let bits = if bits % divisor == 0 {
+            bits / divisor
+        } else {
+            //round half up in the same way as `from_str`, without adding to `bits`
+            let rounding_divisor = divisor * rounding;
+            let remainder = bits % rounding_divisor;
+            match (bits / rounding_divisor + (remainder >= rounding_divisor - remainder) as u128).checked_mul(rounding) {
+                Some(bits) => bits,
+                None => return None,
+            }
+        };
+
+        if bits > u64::MAX as u128 {
+            return None;
+        }
+        Some(MemorySize::from_bits(bits as u64))
+    }
+}
+
+/// Creates a `MemorySize` from a string literal at compile time, failing to compile if the literal is invalid
+/// 
+/// The literal is parsed with `MemorySize::parse_const`
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{memsize, MemorySize};
+///
+/// const PAGE: MemorySize = memsize!("4 KiB");
+/// assert_eq!(PAGE, MemorySize::from_bytes(4096));
+/// assert_eq!(memsize!("100 Mbit"), MemorySize::from_bits(100_000_000));
+/// ```
+///
+/// ```compile_fail
+/// let size = memory_size::memsize!("4 parsecs");
+/// ```
+#[macro_export]
+macro_rules! memsize {
+    ($size:literal) => {
+        const {
+            match $crate::MemorySize::parse_const($size) {
+                Some(size) => size,
+                None => panic!(concat!("invalid memory size: ", $size)),
+            }
+        }
+    };
+}
@@ -0,0 +1,115 @@
+use crate::MemorySize;
+
+/// Reports the deep memory footprint of a value
+/// 
+/// The footprint is split into the inline size of the value itself, and the heap memory that the value owns.
+/// 
+/// With the `derive` feature enabled, this can be derived for structs and enums whose fields all implement `MemSizeOf`
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{MemorySize, MemSizeOf};
+///
+/// let mut data: Vec<u32> = Vec::with_capacity(16);
+/// data.push(1);
+/// // heap usage is based on the capacity, not the length
+/// assert_eq!(data.heap_size_of(), MemorySize::from_bytes(64));
+/// assert_eq!(data.mem_size_of(), MemorySize::from_bytes(64 + std::mem::size_of::<Vec<u32>>() as u64));
+/// ```
+pub trait MemSizeOf {
+    /// Calculates the heap memory owned by `&self`, excluding the inline size of `&self`
+    fn heap_size_of(&self) -> MemorySize;
+
+    /// Calculates the total memory used by `&self`: its inline size plus everything it owns on the heap
+    fn mem_size_of(&self) -> MemorySize {
+        MemorySize::from_bytes(core::mem::size_of_val(self) as u64) + self.heap_size_of()
+    }
+}
+
+/// Implements `MemSizeOf` for types that never own heap memory
+macro_rules! impl_no_heap {
+    ($($t:ty),*) => {
+        $(
+            impl MemSizeOf for $t {
+                fn heap_size_of(&self) -> MemorySize {
+                    MemorySize::new()
+                }
+            }
+        )*
+    };
+}
+
+impl_no_heap!(
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64, bool, char, (), MemorySize
+);
+
+/// References do not own the memory they point to
+impl<T: ?Sized> MemSizeOf for &T {
+    fn heap_size_of(&self) -> MemorySize {
+        MemorySize::new()
+    }
+}
+
+impl<T: MemSizeOf> MemSizeOf for Option<T> {
+    fn heap_size_of(&self) -> MemorySize {
+        self.as_ref().map_or(MemorySize::new(), MemSizeOf::heap_size_of)
+    }
+}
+
+impl<T: MemSizeOf, const N: usize> MemSizeOf for [T; N] {
+    fn heap_size_of(&self) -> MemorySize {
+        self.iter().map(MemSizeOf::heap_size_of).sum()
+    }
+}
+
+impl<A: MemSizeOf, B: MemSizeOf> MemSizeOf for (A, B) {
+    fn heap_size_of(&self) -> MemorySize {
+        self.0.heap_size_of() + self.1.heap_size_of()
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_impls {
+    use super::MemSizeOf;
+    use crate::{estimate, MemorySize};
+    use std::collections::{BTreeMap, HashMap};
+    use std::mem::size_of;
+
+    impl<T: MemSizeOf> MemSizeOf for Box<T> {
+        fn heap_size_of(&self) -> MemorySize {
+            (**self).mem_size_of()
+        }
+    }
+
+    impl MemSizeOf for String {
+        fn heap_size_of(&self) -> MemorySize {
+            estimate::string_heap_size(self.capacity())
+        }
+    }
+
+    impl<T: MemSizeOf> MemSizeOf for Vec<T> {
+        fn heap_size_of(&self) -> MemorySize {
+            let buffer = estimate::vec_heap_size::<T>(self.capacity());
+            buffer + self.iter().map(MemSizeOf::heap_size_of).sum::<MemorySize>()
+        }
+    }
+
+    /// Estimated from the capacity of the table, see `estimate::hash_map_heap_size`
+    impl<K: MemSizeOf, V: MemSizeOf, S> MemSizeOf for HashMap<K, V, S> {
+        fn heap_size_of(&self) -> MemorySize {
+            let table = estimate::hash_map_heap_size::<K, V>(self.capacity());
+            table + self.iter().map(|(k, v)| k.heap_size_of() + v.heap_size_of()).sum::<MemorySize>()
+        }
+    }
+
+    /// Estimated from the stored entries only, so node headers and unused slots in each node are not counted
+    impl<K: MemSizeOf, V: MemSizeOf> MemSizeOf for BTreeMap<K, V> {
+        fn heap_size_of(&self) -> MemorySize {
+            let entries = MemorySize::from_bytes((self.len() * size_of::<(K, V)>()) as u64);
+            entries + self.iter().map(|(k, v)| k.heap_size_of() + v.heap_size_of()).sum::<MemorySize>()
+        }
+    }
+}
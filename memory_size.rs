@@ -9,26 +9,124 @@
 //!
 //! // Create a MemorySize from bytes
 //! let size = MemorySize::from_bytes(1024);
-//! println!("Size: {}", size); // prints "1.00 KB" (depending on the chosen format)
+//! println!("Size: {}", size); // prints "1.00 KiB"
 //! ```
 
+extern crate self as memory_size;
+
 mod tests;
+mod paging;
+mod wasm;
+mod storage;
+mod mem_size_of;
+pub mod estimate;
+mod growth;
+mod error;
+mod split;
+mod size_align;
+mod gpu;
+mod encoding;
+mod compression;
+mod network;
+mod multipart;
+mod raid;
+mod range;
+mod rate;
+mod non_max;
+mod limit;
+mod bounded;
+mod rounding;
+mod arg;
+mod padding;
+mod target;
+mod parse;
+mod literal;
+mod decompose;
+mod arithmetic;
+mod signed;
+mod saturating;
+mod power_of_two;
+#[cfg(feature = "std")]
+mod env;
+pub mod format;
+pub mod http;
+pub mod fallible;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "tokio")]
+pub mod async_io;
+#[cfg(feature = "i18n")]
+pub mod i18n;
+mod interop;
+#[cfg(feature = "std")]
+mod reserve;
+#[cfg(feature = "std")]
+mod c_struct;
+
+pub use mem_size_of::MemSizeOf;
+pub use growth::GrowthPolicy;
+pub use error::MemorySizeError;
+pub use split::{Chunks, SplitEvenly};
+pub use size_align::SizeAlign;
+pub use gpu::GpuLayout;
+pub use encoding::Encoding;
+pub use compression::CompressionRatio;
+pub use network::Segmentation;
+pub use multipart::{MultipartLimits, MultipartPlan};
+pub use raid::{RaidCapacity, RaidLevel};
+pub use range::MemoryRange;
+pub use rate::ByteRate;
+pub use non_max::MemorySizeNonMax;
+pub use limit::{MemoryLimit, ParseLimitError};
+pub use bounded::BoundedSize;
+pub use rounding::RoundingMode;
+pub use arg::SizeArgParser;
+pub use padding::AlignmentPreset;
+pub use target::{Primitive, TargetLayout};
+pub use parse::{MemorySizeParseError, ParseOptions};
+pub use decompose::Decomposed;
+pub use signed::SignedMemorySize;
+pub use saturating::Saturating;
+#[cfg(feature = "std")]
+pub use reserve::TryReserveSize;
+#[cfg(feature = "std")]
+pub use env::FromEnvError;
+#[cfg(feature = "std")]
+pub use c_struct::{CStructError, RustModuleError, StructField, StructLayout};
+#[cfg(feature = "derive")]
+pub use memory_size_derive::MemSizeOf;
 
 use derive_more::{Add, Sub, Sum, AddAssign, SubAssign};
 const BITS_IN_BYTE: u64 = 8;
 
 ///This struct represents the size of an area of memory
 /// The maximum size possible to be represented is u64::MAX bits (approximately 2.3 exabytes)
+/// 
+/// The representation is guaranteed to be the same as a `u64` holding the number of bits
 #[derive(
     PartialEq, PartialOrd, Ord, Eq,
-    Clone, Copy, Hash, Debug, Default,
+    Clone, Copy, Hash, Default,
     Add, Sub, Sum, AddAssign, SubAssign
 )]
+#[repr(transparent)]
 pub struct MemorySize {
     size_bits: u64
 }
 
 impl MemorySize {
+    /// One kibibyte (1024 bytes)
+    pub const KIB: MemorySize = MemorySize::from_kib(1);
+    /// One mebibyte (1024 KiB)
+    pub const MIB: MemorySize = MemorySize::from_mib(1);
+    /// One gibibyte (1024 MiB)
+    pub const GIB: MemorySize = MemorySize::from_gib(1);
+    /// One kilobyte (1000 bytes)
+    pub const KB: MemorySize = MemorySize::from_kb(1);
+    /// One megabyte (1000 kB)
+    pub const MB: MemorySize = MemorySize::from_mb(1);
+    /// One gigabyte (1000 MB)
+    pub const GB: MemorySize = MemorySize::from_gb(1);
+
     /// Generates a new `MemorySize` representing a 0 bits sized object
     /// # Examples
     ///
@@ -97,6 +195,156 @@ impl MemorySize {
         }
     }
 
+
+    /// Constructs a `MemorySize` from a number of kibibytes, each 1024 bytes
+    /// 
+    /// # Panics
+    /// If the size in bits would overflow `u64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_kib(4), MemorySize::from_bytes(4096));
+    /// ```
+    pub const fn from_kib(kib: u64) -> MemorySize {
+        MemorySize::from_bytes(kib.checked_mul(1 << 10).unwrap())
+    }
+
+    /// Constructs a `MemorySize` from a number of mebibytes, each 1024 KiB
+    /// 
+    /// # Panics
+    /// If the size in bits would overflow `u64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_mib(256), MemorySize::from_bytes(256 * 1024 * 1024));
+    /// ```
+    pub const fn from_mib(mib: u64) -> MemorySize {
+        MemorySize::from_bytes(mib.checked_mul(1 << 20).unwrap())
+    }
+
+    /// Constructs a `MemorySize` from a number of gibibytes, each 1024 MiB
+    /// 
+    /// # Panics
+    /// If the size in bits would overflow `u64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_gib(2), MemorySize::from_bytes(2 * 1024 * 1024 * 1024));
+    /// ```
+    pub const fn from_gib(gib: u64) -> MemorySize {
+        MemorySize::from_bytes(gib.checked_mul(1 << 30).unwrap())
+    }
+
+    /// Constructs a `MemorySize` from a number of kilobytes, each 1000 bytes
+    /// 
+    /// # Panics
+    /// If the size in bits would overflow `u64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_kb(4), MemorySize::from_bytes(4_000));
+    /// ```
+    pub const fn from_kb(kb: u64) -> MemorySize {
+        MemorySize::from_bytes(kb.checked_mul(1_000).unwrap())
+    }
+
+    /// Constructs a `MemorySize` from a number of megabytes, each 1000 kB
+    /// 
+    /// # Panics
+    /// If the size in bits would overflow `u64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_mb(256), MemorySize::from_bytes(256_000_000));
+    /// ```
+    pub const fn from_mb(mb: u64) -> MemorySize {
+        MemorySize::from_bytes(mb.checked_mul(1_000_000).unwrap())
+    }
+
+    /// Constructs a `MemorySize` from a number of gigabytes, each 1000 MB
+    /// 
+    /// # Panics
+    /// If the size in bits would overflow `u64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_gb(2), MemorySize::from_bytes(2_000_000_000));
+    /// ```
+    pub const fn from_gb(gb: u64) -> MemorySize {
+        MemorySize::from_bytes(gb.checked_mul(1_000_000_000).unwrap())
+    }
+
+    /// Constructs a `MemorySize` from a fractional number of bytes, like a size read from a config file
+    /// 
+    /// The result is rounded to the nearest whole bit, with ties rounding up.
+    /// The `try_from_*_f64` constructors for the other units work in the same way
+    /// 
+    /// # Errors
+    /// - `MemorySizeError::InvalidFactor` if `bytes` is NaN or infinite
+    /// - `MemorySizeError::Negative` if `bytes` is negative
+    /// - `MemorySizeError::Overflow` if the size does not fit in a `MemorySize`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, MemorySizeError};
+    ///
+    /// assert_eq!(MemorySize::try_from_bytes_f64(12.5), Ok(MemorySize::from_bits(100)));
+    /// assert_eq!(MemorySize::try_from_gib_f64(1.5), Ok(MemorySize::from_mib(1536)));
+    /// assert_eq!(MemorySize::try_from_bytes_f64(-1.0), Err(MemorySizeError::Negative));
+    /// assert_eq!(MemorySize::try_from_bytes_f64(f64::NAN), Err(MemorySizeError::InvalidFactor));
+    /// ```
+    pub fn try_from_bytes_f64(bytes: f64) -> Result<MemorySize, MemorySizeError> {
+        MemorySize::from_bytes(1).mul_f64(bytes, RoundingMode::Nearest)
+    }
+
+    /// Constructs a `MemorySize` from a fractional number of kibibytes, rounded like `try_from_bytes_f64`
+    pub fn try_from_kib_f64(kib: f64) -> Result<MemorySize, MemorySizeError> {
+        MemorySize::KIB.mul_f64(kib, RoundingMode::Nearest)
+    }
+
+    /// Constructs a `MemorySize` from a fractional number of mebibytes, rounded like `try_from_bytes_f64`
+    pub fn try_from_mib_f64(mib: f64) -> Result<MemorySize, MemorySizeError> {
+        MemorySize::MIB.mul_f64(mib, RoundingMode::Nearest)
+    }
+
+    /// Constructs a `MemorySize` from a fractional number of gibibytes, rounded like `try_from_bytes_f64`
+    pub fn try_from_gib_f64(gib: f64) -> Result<MemorySize, MemorySizeError> {
+        MemorySize::GIB.mul_f64(gib, RoundingMode::Nearest)
+    }
+
+    /// Constructs a `MemorySize` from a fractional number of kilobytes, rounded like `try_from_bytes_f64`
+    pub fn try_from_kb_f64(kb: f64) -> Result<MemorySize, MemorySizeError> {
+        MemorySize::KB.mul_f64(kb, RoundingMode::Nearest)
+    }
+
+    /// Constructs a `MemorySize` from a fractional number of megabytes, rounded like `try_from_bytes_f64`
+    pub fn try_from_mb_f64(mb: f64) -> Result<MemorySize, MemorySizeError> {
+        MemorySize::MB.mul_f64(mb, RoundingMode::Nearest)
+    }
+
+    /// Constructs a `MemorySize` from a fractional number of gigabytes, rounded like `try_from_bytes_f64`
+    pub fn try_from_gb_f64(gb: f64) -> Result<MemorySize, MemorySizeError> {
+        MemorySize::GB.mul_f64(gb, RoundingMode::Nearest)
+    }
     
     /// Returns the size in bytes.
     ///
@@ -113,7 +361,7 @@ impl MemorySize {
     /// assert_eq!(size.size_bytes(), 64);
     /// ```
     pub const fn size_bytes(&self) -> u64 {
-        assert!(self.size_bits % BITS_IN_BYTE == 0);
+        assert!(self.size_bits.is_multiple_of(BITS_IN_BYTE));
         self.size_bits / BITS_IN_BYTE
     }
 
@@ -150,6 +398,9 @@ impl MemorySize {
     /// 
     /// i.e returned value is a multiple of alignment
     /// 
+    /// # Panics
+    /// if the aligned size overflows
+    /// 
     /// # Examples
     ///
     /// ```
@@ -159,15 +410,99 @@ impl MemorySize {
     /// assert_eq!(size.align_up(MemorySize::from_bytes(4)), MemorySize::from_bytes(28));
     /// ```
     pub const fn align_up(&self, alignment: MemorySize) -> MemorySize {
-        //address 0 is aligned to everything
-        if self.size_bits == 0 {return *self;}
+        self.checked_align_up(alignment).unwrap()
+    }
+
+    /// Calculates the memory size above or equal to `self` that is aligned to `alignment`
+    /// 
+    /// A zero alignment leaves `self` unchanged, like in `align_up`. Returns `None` if the aligned size overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let four = MemorySize::from_bytes(4);
+    /// assert_eq!(MemorySize::from_bytes(25).checked_align_up(four), Some(MemorySize::from_bytes(28)));
+    /// assert_eq!(MemorySize::from_bits(u64::MAX).checked_align_up(four), None);
+    /// ```
+    pub const fn checked_align_up(&self, alignment: MemorySize) -> Option<MemorySize> {
         // alignment 0 = no alignment
+        if alignment.size_bits == 0 {return Some(*self);}
+
+        //step up by the distance to the next multiple, which only overflows if the result does
+        let remainder = self.size_bits % alignment.size_bits;
+        if remainder == 0 {return Some(*self);}
+        match self.size_bits.checked_add(alignment.size_bits - remainder) {
+            Some(size_bits) => Some(Self { size_bits }),
+            None => None,
+        }
+    }
+
+    /// Calculates the memory size below or equal to `self` that is aligned to `alignment`
+    /// 
+    /// A zero alignment leaves `self` unchanged, like in `align_up`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let page = MemorySize::from_bytes(4096);
+    /// assert_eq!(MemorySize::from_bytes(10_000).align_down(page), MemorySize::from_bytes(8192));
+    /// assert_eq!(MemorySize::from_bytes(8192).align_down(page), MemorySize::from_bytes(8192));
+    /// ```
+    pub const fn align_down(&self, alignment: MemorySize) -> MemorySize {
         if alignment.size_bits == 0 {return *self;}
 
-        let too_much = self.size_bits + alignment.size_bits - 1;//go above self
-        let size_bits = (too_much / alignment.size_bits) * alignment.size_bits;//round down
+        Self { size_bits: self.size_bits - self.size_bits % alignment.size_bits }
+    }
 
-        Self { size_bits }
+    /// Calculates the memory size closest to `self` that is aligned to `alignment`, rounding halfway sizes up
+    /// 
+    /// A zero alignment leaves `self` unchanged, like in `align_up`
+    /// 
+    /// # Panics
+    /// if rounding up overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let granule = MemorySize::from_bytes(4);
+    /// assert_eq!(MemorySize::from_bytes(9).align_nearest(granule), MemorySize::from_bytes(8));
+    /// assert_eq!(MemorySize::from_bytes(10).align_nearest(granule), MemorySize::from_bytes(12));
+    /// ```
+    pub const fn align_nearest(&self, alignment: MemorySize) -> MemorySize {
+        if alignment.size_bits == 0 {return *self;}
+
+        let remainder = self.size_bits % alignment.size_bits;
+        if remainder < alignment.size_bits - remainder {
+            self.align_down(alignment)
+        } else {
+            self.align_up(alignment)
+        }
+    }
+
+    /// Calculates the memory size above or equal to `self` that is aligned to every alignment in `alignments`
+    /// 
+    /// This rounds up once to the least common multiple of the alignments. Zero alignments are ignored, like in `align_up`
+    /// 
+    /// # Panics
+    /// if the least common multiple of the alignments overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let size = MemorySize::from_bytes(100);
+    /// let alignments = [MemorySize::from_bytes(8), MemorySize::from_bytes(12)];
+    /// assert_eq!(size.align_up_all(&alignments), MemorySize::from_bytes(120));
+    /// ```
+    pub const fn align_up_all(&self, alignments: &[MemorySize]) -> MemorySize {
+        fallible::align_up_all(*self, alignments).unwrap()
     }
 
     /// Calculates the minimum number of bytes that can store `&self`
@@ -185,24 +520,126 @@ impl MemorySize {
         self.align_up(MemorySize::from_bytes(1))
     }
 
+    /// Checks whether `&self` is an exact multiple of `other`
+    /// 
+    /// A zero `other` never divides evenly
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let record = MemorySize::from_bytes(12);
+    /// assert!(MemorySize::from_bytes(120).divides_evenly(record));
+    /// assert!(!MemorySize::from_bytes(121).divides_evenly(record));
+    /// ```
+    pub const fn divides_evenly(&self, other: MemorySize) -> bool {
+        other.size_bits != 0 && self.size_bits.is_multiple_of(other.size_bits)
+    }
+
+    /// Calculates how many times `other` fits into `&self`, only if it fits exactly
+    /// 
+    /// Returns `None` if `other` is zero or does not divide `&self` evenly
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let record = MemorySize::from_bytes(12);
+    /// assert_eq!(MemorySize::from_bytes(120).checked_exact_div(record), Some(10));
+    /// assert_eq!(MemorySize::from_bytes(121).checked_exact_div(record), None);
+    /// ```
+    pub const fn checked_exact_div(&self, other: MemorySize) -> Option<u64> {
+        if self.divides_evenly(other) {
+            Some(self.size_bits / other.size_bits)
+        } else {
+            None
+        }
+    }
+
+    /// Adds up every size in `sizes`
+    /// 
+    /// The total is accumulated in a `u128`, so only the final result needs an overflow check
+    /// 
+    /// # Errors
+    /// `MemorySizeError::Overflow` if the total does not fit in a `MemorySize`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let sizes = [MemorySize::from_bytes(3), MemorySize::from_bits(4)];
+    /// assert_eq!(MemorySize::sum_slice(&sizes), Ok(MemorySize::from_bits(28)));
+    /// assert!(MemorySize::sum_slice(&[MemorySize::from_bits(u64::MAX); 2]).is_err());
+    /// ```
+    pub fn sum_slice(sizes: &[MemorySize]) -> Result<MemorySize, MemorySizeError> {
+        let total: u128 = sizes.iter().map(|size| size.size_bits as u128).sum();
+        u64::try_from(total)
+            .map(MemorySize::from_bits)
+            .map_err(|_| MemorySizeError::Overflow)
+    }
+
 }
 
-#[cfg(feature = "std")]
-impl std::fmt::Display for MemorySize {
-    
-    /// Formats the `MemorySize` in a human-readable way.
+/// Calculates the greatest common divisor of `a` and `b`
+const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Calculates the least common multiple of two non-zero numbers
+/// 
+/// # Panics
+/// if the result overflows
+const fn lcm(a: u64, b: u64) -> u64 {
+    checked_lcm(a, b).unwrap()
+}
+
+/// Calculates the least common multiple of two non-zero numbers, or `None` if it overflows
+const fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+impl core::fmt::Debug for MemorySize {
+    /// Formats the number of bits, like `MemorySize { size_bits: 8192 }`
+    ///
+    /// The alternate flag `{:#?}` adds the human-readable size on the same line, like `MemorySize { size_bits: 8192 /* 1.00 KiB */ }`
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            write!(f, "MemorySize {{ size_bits: {} /* {} */ }}", self.size_bits, self.display_human())
+        } else {
+            f.debug_struct("MemorySize").field("size_bits", &self.size_bits).finish()
+        }
+    }
+}
+
+impl core::fmt::Display for MemorySize {
+    /// Formats the `MemorySize` in a human-readable way, to two decimal places in binary units
     ///
-    /// Uses the `humansize` crate to format the size to two decimal places.
+    /// Use `display_with` to choose decimal units or a different precision, or `display_round_trip` for the exact size.
+    /// The width, fill, alignment and precision of the formatter are honored, and sizes are right-aligned by default.
+    /// The alternate flag `{:#}` formats the exact number of bytes instead, or of bits if the size is not a whole number of bytes
     ///
     /// # Examples
     ///
     /// ```
     /// use memory_size::MemorySize;
     ///
-    /// let size = MemorySize::from_bits(64);
-    /// println!("{}", size); // e.g. "64bit"
+    /// assert_eq!(MemorySize::from_bytes(1024).to_string(), "1.00 KiB");
+    /// assert_eq!(MemorySize::from_bits(64).to_string(), "8 B");
+    /// assert_eq!(format!("[{:>10.1}]", MemorySize::from_bytes(1536)), "[   1.5 KiB]");
+    /// assert_eq!(format!("{:#}", MemorySize::from_bytes(1048576)), "1048576 B");
+    /// assert_eq!(format!("{:#}", MemorySize::from_bits(8193)), "8193 bits");
     /// ```
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}bit", self.size_bits())
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            self.display_exact().fmt(f)
+        } else {
+            self.display_human().fmt(f)
+        }
     }
-}
\ No newline at end of file
+}
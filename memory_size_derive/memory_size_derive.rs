@@ -0,0 +1,81 @@
+//! Derive macro for `memory_size::MemSizeOf`
+//! 
+//! This crate is re-exported by `memory_size` when the `derive` feature is enabled, and should not need to be used directly
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields};
+
+/// Derives `MemSizeOf` by summing the heap usage of every field
+/// 
+/// Every field must implement `MemSizeOf`, and every generic type parameter is required to implement it too
+#[proc_macro_derive(MemSizeOf)]
+pub fn derive_mem_size_of(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+
+    for param in input.generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::memory_size::MemSizeOf));
+    }
+
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, sum) = fields_heap_size(&data.fields);
+            quote! {
+                let #name #pattern = self;
+                #sum
+            }
+        }
+        //a reference to an empty enum is not itself uninhabited, so it has to be dereferenced for the match to be exhaustive
+        Data::Enum(data) if data.variants.is_empty() => quote! { match *self {} },
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let variant_name = &variant.ident;
+                let (pattern, sum) = fields_heap_size(&variant.fields);
+                quote! { #name::#variant_name #pattern => { #sum } }
+            });
+            quote! {
+                #[allow(unreachable_code)]
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => {
+            return syn::Error::new_spanned(&input.ident, "MemSizeOf cannot be derived for unions")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        impl #impl_generics ::memory_size::MemSizeOf for #name #ty_generics #where_clause {
+            fn heap_size_of(&self) -> ::memory_size::MemorySize {
+                #body
+            }
+        }
+    }
+    .into()
+}
+
+/// Generates a destructuring pattern binding every field, and an expression summing the heap size of each binding
+fn fields_heap_size(fields: &Fields) -> (TokenStream2, TokenStream2) {
+    let bindings: Vec<_> = (0..fields.len()).map(|i| format_ident!("__field_{}", i)).collect();
+
+    let pattern = match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|field| field.ident.as_ref().unwrap());
+            quote! { { #(#names: #bindings),* } }
+        }
+        Fields::Unnamed(_) => quote! { ( #(#bindings),* ) },
+        Fields::Unit => quote! {},
+    };
+
+    let sum = quote! {
+        ::memory_size::MemorySize::new() #(+ ::memory_size::MemSizeOf::heap_size_of(#bindings))*
+    };
+
+    (pattern, sum)
+}
@@ -0,0 +1,96 @@
+use crate::{MemorySize, BITS_IN_BYTE};
+
+/// Limits on the parts of a multipart upload
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct MultipartLimits {
+    /// Smallest allowed part, except for the last part which may be smaller
+    pub min_part_size: MemorySize,
+    /// Largest allowed part
+    pub max_part_size: MemorySize,
+    /// Largest number of parts in one upload
+    pub max_parts: u64,
+}
+
+/// A part size and count for uploading an object, calculated by `MultipartLimits::plan`
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct MultipartPlan {
+    /// Size of every part except the last
+    pub part_size: MemorySize,
+    /// Number of parts
+    pub part_count: u64,
+    /// Size of the last part, which holds whatever is left over
+    pub last_part_size: MemorySize,
+}
+
+impl MultipartLimits {
+    /// The limits of Amazon S3: parts from 5 MiB to 5 GiB, and at most 10,000 parts
+    pub const S3: MultipartLimits = MultipartLimits {
+        min_part_size: MemorySize::from_bytes(5 << 20),
+        max_part_size: MemorySize::from_bytes(5 << 30),
+        max_parts: 10_000,
+    };
+
+    /// Chooses the smallest valid part size for uploading an object of size `total`, and the resulting number of parts
+    /// 
+    /// Parts are whole numbers of bytes. An empty object is uploaded as a single empty part
+    /// 
+    /// Returns `None` if the object cannot be uploaded within the limits
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, MultipartLimits};
+    ///
+    /// // 100 GiB needs parts larger than the 5 MiB minimum to stay within 10,000 parts
+    /// let plan = MultipartLimits::S3.plan(MemorySize::from_bytes(100 << 30)).unwrap();
+    /// assert_eq!(plan.part_count, 10_000);
+    /// assert_eq!(plan.part_size, MemorySize::from_bytes(10737419));
+    ///
+    /// // more than 10,000 of the largest parts
+    /// assert_eq!(MultipartLimits::S3.plan(MemorySize::from_bytes((5 << 30) * 10_000 + 1)), None);
+    /// ```
+    pub const fn plan(&self, total: MemorySize) -> Option<MultipartPlan> {
+        if self.max_parts == 0 {
+            return None;
+        }
+        if total.size_bits() == 0 {
+            return Some(MultipartPlan { part_size: MemorySize::new(), part_count: 1, last_part_size: MemorySize::new() });
+        }
+
+        //smallest part that keeps within the maximum number of parts, which is too large to upload if rounding it to a byte overflows
+        let Some(needed) = total.size_bits().div_ceil(self.max_parts).checked_next_multiple_of(BITS_IN_BYTE) else {return None};
+        let Some(minimum) = self.min_part_size.checked_align_up(MemorySize::from_bytes(1)) else {return None};
+        let minimum = minimum.size_bits();
+        let part_bits = if needed > minimum {needed} else {minimum};
+        if part_bits > self.max_part_size.size_bits() {
+            return None;
+        }
+
+        let part_count = total.size_bits().div_ceil(part_bits);
+        let last_part_bits = total.size_bits() - (part_count - 1) * part_bits;
+
+        Some(MultipartPlan {
+            part_size: MemorySize::from_bits(part_bits),
+            part_count,
+            last_part_size: MemorySize::from_bits(last_part_bits),
+        })
+    }
+}
+
+impl MultipartPlan {
+    /// Iterates over the size of each part in order
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, MultipartLimits};
+    ///
+    /// let plan = MultipartLimits::S3.plan(MemorySize::from_bytes(12 << 20)).unwrap();
+    /// let parts: Vec<_> = plan.parts().collect();
+    /// assert_eq!(parts, [5 << 20, 5 << 20, 2 << 20].map(MemorySize::from_bytes));
+    /// ```
+    pub fn parts(&self) -> impl Iterator<Item = MemorySize> {
+        core::iter::repeat_n(self.part_size, (self.part_count - 1) as usize)
+            .chain(core::iter::once(self.last_part_size))
+    }
+}
@@ -0,0 +1,51 @@
+use crate::MemorySize;
+
+/// The result of splitting a payload into packets, calculated by `MemorySize::segment`
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct Segmentation {
+    /// Number of packets needed to send the payload
+    pub packet_count: u64,
+    /// Size of the final packet, including its header
+    pub last_packet_size: MemorySize,
+    /// Total size of every packet, including headers
+    pub on_wire_size: MemorySize,
+}
+
+impl MemorySize {
+    /// Calculates how a payload of size `&self` is split into packets of at most `mtu`, each starting with a header of size `header`
+    /// 
+    /// The MTU includes the header, so each packet carries `mtu - header` of the payload. An empty payload needs no packets
+    /// 
+    /// # Panics
+    /// if `header` is not smaller than `mtu`, or the on-wire size overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// // 4000 bytes over Ethernet with IPv4 and TCP headers: 1460 byte payloads
+    /// let plan = MemorySize::from_bytes(4000).segment(MemorySize::from_bytes(1500), MemorySize::from_bytes(40));
+    /// assert_eq!(plan.packet_count, 3);
+    /// assert_eq!(plan.last_packet_size, MemorySize::from_bytes(1080 + 40));
+    /// assert_eq!(plan.on_wire_size, MemorySize::from_bytes(4000 + 3 * 40));
+    /// ```
+    pub const fn segment(&self, mtu: MemorySize, header: MemorySize) -> Segmentation {
+        assert!(header.size_bits < mtu.size_bits, "header must be smaller than the MTU");
+        let per_packet = mtu.size_bits - header.size_bits;
+
+        let packet_count = self.size_bits.div_ceil(per_packet);
+        if packet_count == 0 {
+            return Segmentation { packet_count, last_packet_size: MemorySize::new(), on_wire_size: MemorySize::new() };
+        }
+
+        let last_payload = self.size_bits - (packet_count - 1) * per_packet;
+        let headers = packet_count.checked_mul(header.size_bits).unwrap();
+
+        Segmentation {
+            packet_count,
+            last_packet_size: MemorySize::from_bits(last_payload + header.size_bits),
+            on_wire_size: MemorySize::from_bits(self.size_bits.checked_add(headers).unwrap()),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::MemorySize;
+use core::cmp::Ordering;
+use core::num::NonZeroU64;
+
+/// A `MemorySize` that cannot be `u64::MAX` bits
+/// 
+/// Giving up the largest value lets `Option<MemorySizeNonMax>` be the same size as `MemorySize`,
+/// which matters when storing large numbers of optional sizes
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{MemorySize, MemorySizeNonMax};
+///
+/// assert_eq!(size_of::<Option<MemorySizeNonMax>>(), size_of::<MemorySize>());
+///
+/// let size = MemorySizeNonMax::new(MemorySize::from_bytes(16)).unwrap();
+/// assert_eq!(size.get(), MemorySize::from_bytes(16));
+/// assert_eq!(MemorySizeNonMax::new(MemorySize::from_bits(u64::MAX)), None);
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Hash)]
+pub struct MemorySizeNonMax {
+    /// The bitwise inverse of the number of bits, which is never zero
+    inverted_bits: NonZeroU64,
+}
+
+impl MemorySizeNonMax {
+    /// The largest size that can be stored, one bit less than `u64::MAX` bits
+    pub const MAX: MemorySizeNonMax = match MemorySizeNonMax::new(MemorySize::from_bits(u64::MAX - 1)) {
+        Some(x) => x,
+        None => unreachable!(),
+    };
+
+    /// Converts `size` into a `MemorySizeNonMax`, returning `None` if it is `u64::MAX` bits
+    pub const fn new(size: MemorySize) -> Option<MemorySizeNonMax> {
+        match NonZeroU64::new(!size.size_bits()) {
+            Some(inverted_bits) => Some(MemorySizeNonMax { inverted_bits }),
+            None => None,
+        }
+    }
+
+    /// Returns the stored size
+    pub const fn get(&self) -> MemorySize {
+        MemorySize::from_bits(!self.inverted_bits.get())
+    }
+}
+
+impl Default for MemorySizeNonMax {
+    fn default() -> Self {
+        MemorySizeNonMax { inverted_bits: NonZeroU64::MAX }
+    }
+}
+
+impl PartialOrd for MemorySizeNonMax {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MemorySizeNonMax {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get().cmp(&other.get())
+    }
+}
+
+impl core::fmt::Debug for MemorySizeNonMax {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("MemorySizeNonMax").field(&self.get()).finish()
+    }
+}
+
+impl From<MemorySizeNonMax> for MemorySize {
+    fn from(value: MemorySizeNonMax) -> Self {
+        value.get()
+    }
+}
@@ -0,0 +1,94 @@
+use crate::MemorySize;
+
+/// Commonly needed alignments that sizes can be padded to
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum AlignmentPreset {
+    /// `MemorySize::CACHE_LINE` for the target being compiled for
+    CacheLine,
+    /// A 4 KiB memory page
+    Page,
+    /// A 2 MiB huge page, as used on x86_64 and AArch64
+    HugePage,
+    /// `MemorySize::GPU_BUFFER_OFFSET_ALIGNMENT`
+    GpuBufferOffset,
+}
+
+impl AlignmentPreset {
+    /// Returns the alignment this preset stands for
+    pub const fn alignment(&self) -> MemorySize {
+        match self {
+            AlignmentPreset::CacheLine => MemorySize::CACHE_LINE,
+            AlignmentPreset::Page => MemorySize::from_bytes(4096),
+            AlignmentPreset::HugePage => MemorySize::from_bytes(2 << 20),
+            AlignmentPreset::GpuBufferOffset => MemorySize::GPU_BUFFER_OFFSET_ALIGNMENT,
+        }
+    }
+}
+
+impl MemorySize {
+    /// The size that values must be padded to so that they never share a cache line, for the target being compiled for
+    /// 
+    /// This is 128 bytes on x86_64, AArch64 and powerpc64, where adjacent lines are prefetched in pairs,
+    /// 256 bytes on s390x, and 64 bytes elsewhere
+    pub const CACHE_LINE: MemorySize = MemorySize::from_bytes(
+        if cfg!(any(target_arch = "x86_64", target_arch = "aarch64", target_arch = "powerpc64")) {128}
+        else if cfg!(target_arch = "s390x") {256}
+        else {64}
+    );
+    /// The 64 byte cache line used by most x86_64 and AArch64 cores, independent of the target being compiled for
+    pub const CACHE_LINE_64: MemorySize = MemorySize::from_bytes(64);
+
+    /// Pads `&self` up to a multiple of `MemorySize::CACHE_LINE`, returning the padded size and the padding added
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let (padded, padding) = MemorySize::from_bytes(24).pad_to_cache_line();
+    /// assert_eq!(padded, MemorySize::CACHE_LINE);
+    /// assert_eq!(padded - padding, MemorySize::from_bytes(24));
+    /// ```
+    pub const fn pad_to_cache_line(&self) -> (MemorySize, MemorySize) {
+        self.pad_to(AlignmentPreset::CacheLine)
+    }
+
+    /// Pads `&self` up to a multiple of the alignment of `preset`, returning the padded size and the padding added
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{AlignmentPreset, MemorySize};
+    ///
+    /// let (padded, padding) = MemorySize::from_bytes(5000).pad_to(AlignmentPreset::Page);
+    /// assert_eq!(padded, MemorySize::from_bytes(8192));
+    /// assert_eq!(padding, MemorySize::from_bytes(3192));
+    /// ```
+    pub const fn pad_to(&self, preset: AlignmentPreset) -> (MemorySize, MemorySize) {
+        let padded = self.align_up(preset.alignment());
+        (padded, self.padding_needed_for(preset.alignment()))
+    }
+
+    /// Calculates the padding to add after `&self` to reach a multiple of `alignment`, like `Layout::padding_needed_for`
+    /// 
+    /// A zero alignment needs no padding, like in `align_up`. This never overflows, even if the padded size would
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let eight = MemorySize::from_bytes(8);
+    /// assert_eq!(MemorySize::from_bytes(13).padding_needed_for(eight), MemorySize::from_bytes(3));
+    /// assert_eq!(MemorySize::from_bytes(16).padding_needed_for(eight), MemorySize::new());
+    /// ```
+    pub const fn padding_needed_for(&self, alignment: MemorySize) -> MemorySize {
+        if alignment.size_bits() == 0 {
+            return MemorySize::new();
+        }
+        match self.size_bits() % alignment.size_bits() {
+            0 => MemorySize::new(),
+            remainder => MemorySize::from_bits(alignment.size_bits() - remainder),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{MemoryRange, MemorySize};
+
+/// Size of a single page table, as used by x86_64 and AArch64 (4 KiB granule)
+const PAGE_TABLE_SIZE: MemorySize = MemorySize::from_bytes(4096);
+/// Size of a single page table entry
+const PAGE_TABLE_ENTRY_SIZE: MemorySize = MemorySize::from_bytes(8);
+
+impl MemorySize {
+    /// The size of a base page on x86_64 and most AArch64 systems
+    pub const PAGE_4K: MemorySize = MemorySize::from_kib(4);
+    /// The size of a huge page mapped by a second-level entry on x86_64 and AArch64 (4 KiB granule)
+    pub const PAGE_2M: MemorySize = MemorySize::from_mib(2);
+    /// The size of a huge page mapped by a third-level entry on x86_64 and AArch64 (4 KiB granule)
+    pub const PAGE_1G: MemorySize = MemorySize::from_gib(1);
+
+    /// Estimates the memory taken by the page tables needed to map a region of `&self`
+    /// 
+    /// `page_size` is the size of the pages mapped by the leaf entries, and `levels` is the paging depth down to those leaves
+    /// (e.g. 4 levels for 4 KiB pages on x86_64, or 3 levels when mapping 2 MiB huge pages)
+    /// 
+    /// Assumes 4 KiB tables of 8 byte entries, and that the region starts on a boundary covered by a single top-level table
+    /// 
+    /// # Panics
+    /// if `page_size` is zero, or the total size overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// // 1 GiB of 4 KiB pages: 512 leaf tables, plus one table at each of the 3 levels above
+    /// let region = MemorySize::from_bytes(1 << 30);
+    /// let overhead = region.page_table_overhead(MemorySize::from_bytes(4096), 4);
+    /// assert_eq!(overhead, MemorySize::from_bytes(515 * 4096));
+    /// ```
+    pub const fn page_table_overhead(&self, page_size: MemorySize, levels: u32) -> MemorySize {
+        assert!(page_size.size_bits != 0, "page size must be non-zero");
+        let entries_per_table = PAGE_TABLE_SIZE.size_bits / PAGE_TABLE_ENTRY_SIZE.size_bits;
+
+        let mut entries = self.size_bits.div_ceil(page_size.size_bits);//leaf entries needed to map the region
+        let mut total_tables = 0;
+        let mut level = 0;
+        while level < levels {
+            let tables = entries.div_ceil(entries_per_table);
+            total_tables += tables;
+            entries = tables;//each table is pointed to by an entry one level up
+            level += 1;
+        }
+
+        MemorySize::from_bits(total_tables.checked_mul(PAGE_TABLE_SIZE.size_bits).unwrap())
+    }
+
+    /// Calculates the reservation needed for `&self` rounded up to whole pages, with `guard_pages` guard pages on each side
+    /// 
+    /// Returns the total size to reserve, and the usable range inside it, which starts after the leading guard pages
+    /// 
+    /// # Panics
+    /// if `page_size` is zero, or the total size overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let page = MemorySize::from_bytes(4096);
+    /// let (total, usable) = MemorySize::from_bytes(10_000).with_guard_pages(page, 1);
+    /// assert_eq!(total, MemorySize::from_bytes(5 * 4096));
+    /// assert_eq!(usable.start(), page);
+    /// assert_eq!(usable.len(), MemorySize::from_bytes(3 * 4096));
+    /// ```
+    pub const fn with_guard_pages(&self, page_size: MemorySize, guard_pages: u64) -> (MemorySize, MemoryRange) {
+        assert!(page_size.size_bits != 0, "page size must be non-zero");
+
+        let usable_pages = self.size_bits.div_ceil(page_size.size_bits);
+        let guard = guard_pages.checked_mul(page_size.size_bits).unwrap();
+        let usable = usable_pages.checked_mul(page_size.size_bits).unwrap();
+        let total = guard.checked_mul(2).unwrap().checked_add(usable).unwrap();
+
+        (
+            MemorySize::from_bits(total),
+            MemoryRange::from_start_len(MemorySize::from_bits(guard), MemorySize::from_bits(usable)),
+        )
+    }
+}
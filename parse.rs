@@ -0,0 +1,509 @@
+use crate::{MemorySize, BITS_IN_BYTE};
+
+/// Errors from parsing a `MemorySize`, recording the byte offset in the input where parsing failed
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum MemorySizeParseError {
+    /// The input was empty or only whitespace
+    Empty,
+    /// The number was missing or badly formatted
+    InvalidNumber {
+        /// Offset of the start of the number
+        offset: usize,
+    },
+    /// The unit after the number was not recognised
+    UnknownUnit {
+        /// Offset of the start of the unit
+        offset: usize,
+        /// The known unit that the unit was probably a misspelling of, if any
+        suggestion: Option<&'static str>,
+    },
+    /// The size was too large to represent
+    Overflow {
+        /// Offset of the start of the number
+        offset: usize,
+    },
+    /// The size was not a whole number of bytes, and `ParseOptions::reject_fractional_bytes` was set
+    FractionalBytes {
+        /// Offset of the start of the number
+        offset: usize,
+    },
+}
+
+impl MemorySizeParseError {
+    /// Returns the byte offset in the input where parsing failed, which is zero for empty input
+    pub const fn offset(&self) -> usize {
+        match self {
+            MemorySizeParseError::Empty => 0,
+            MemorySizeParseError::InvalidNumber { offset }
+            | MemorySizeParseError::UnknownUnit { offset, .. }
+            | MemorySizeParseError::Overflow { offset }
+            | MemorySizeParseError::FractionalBytes { offset } => *offset,
+        }
+    }
+
+    /// Returns the known unit that an unrecognised unit was probably a misspelling of, if any
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, ParseOptions};
+    ///
+    /// let err = "10 megabtyes".parse::<MemorySize>().unwrap_err();
+    /// assert_eq!(err.suggestion(), Some("megabytes"));
+    /// assert_eq!(err.to_string(), "unknown unit at byte 3, did you mean \"megabytes\"?");
+    ///
+    /// let strict = ParseOptions::new().case_sensitive(true);
+    /// assert_eq!(MemorySize::parse_with("10 GIb", &strict).unwrap_err().suggestion(), Some("GiB"));
+    /// ```
+    pub const fn suggestion(&self) -> Option<&'static str> {
+        match self {
+            MemorySizeParseError::UnknownUnit { suggestion, .. } => *suggestion,
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for MemorySizeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemorySizeParseError::Empty => write!(f, "empty size, expected a number followed by a unit"),
+            MemorySizeParseError::InvalidNumber { offset } => write!(f, "invalid number at byte {}", offset),
+            MemorySizeParseError::UnknownUnit { offset, suggestion: Some(unit) } => write!(f, "unknown unit at byte {}, did you mean \"{}\"?", offset, unit),
+            MemorySizeParseError::UnknownUnit { offset, suggestion: None } => write!(f, "unknown unit at byte {}", offset),
+            MemorySizeParseError::Overflow { offset } => write!(f, "size at byte {} is too large to represent", offset),
+            MemorySizeParseError::FractionalBytes { offset } => write!(f, "size at byte {} is not a whole number of bytes", offset),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MemorySizeParseError {}
+
+/// Decimal (SI) and binary (IEC) prefixes, with the SI letter and their full names
+/// 
+/// The binary letter is always the uppercase SI letter. `MemorySize::parse_const` reads the same table
+pub(crate) const PREFIXES: [(char, &str, &str, u128); 6] = [
+    ('k', "kilo", "kibi", 1),
+    ('M', "mega", "mebi", 2),
+    ('G', "giga", "gibi", 3),
+    ('T', "tera", "tebi", 4),
+    ('P', "peta", "pebi", 5),
+    ('E', "exa", "exbi", 6),
+];
+
+/// The units suggested for misspellings, in the standard case, with binary units before decimal ones
+/// 
+/// Every name here is accepted by `parse_unit`
+pub(crate) const UNIT_NAMES: [&str; 53] = [
+    "B", "byte", "bytes", "bit", "bits",
+    "KiB", "kB", "Kibit", "kbit", "kibibytes", "kilobytes", "kibibits", "kilobits",
+    "MiB", "MB", "Mibit", "Mbit", "mebibytes", "megabytes", "mebibits", "megabits",
+    "GiB", "GB", "Gibit", "Gbit", "gibibytes", "gigabytes", "gibibits", "gigabits",
+    "TiB", "TB", "Tibit", "Tbit", "tebibytes", "terabytes", "tebibits", "terabits",
+    "PiB", "PB", "Pibit", "Pbit", "pebibytes", "petabytes", "pebibits", "petabits",
+    "EiB", "EB", "Eibit", "Ebit", "exbibytes", "exabytes", "exbibits", "exabits",
+];
+
+/// The most fractional digits accepted, so that the scale always fits in a `u128`
+const MAX_FRACTION_DIGITS: u32 = 19;
+
+/// A decimal number split into its digits and the power of ten they are divided by
+struct Decimal {
+    mantissa: u128,
+    scale: u32,
+}
+
+/// Options controlling how `MemorySize::parse_with` reads sizes
+/// 
+/// The default options match `MemorySize::from_str`
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{MemorySize, ParseOptions};
+///
+/// // as written in many European locales
+/// let options = ParseOptions::new().digit_separators(',', Some('.'));
+/// assert_eq!(MemorySize::parse_with("1.048.576 B", &options), Ok(MemorySize::from_bytes(1 << 20)));
+/// assert_eq!(MemorySize::parse_with("1,5 KiB", &options), Ok(MemorySize::from_bytes(1536)));
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ParseOptions {
+    decimal_separator: char,
+    thousands_separator: Option<char>,
+    case_sensitive: bool,
+    bare_numbers_are_bits: bool,
+    si_as_binary: bool,
+    reject_fractional_bytes: bool,
+}
+
+impl ParseOptions {
+    /// Creates the default options: a `.` decimal separator and no thousands separator,
+    /// case-insensitive units, bare numbers in bytes, decimal SI prefixes, and rounded fractions
+    pub const fn new() -> ParseOptions {
+        ParseOptions {
+            decimal_separator: '.',
+            thousands_separator: None,
+            case_sensitive: false,
+            bare_numbers_are_bits: false,
+            si_as_binary: false,
+            reject_fractional_bytes: false,
+        }
+    }
+
+    /// Sets the character between the whole and fractional part of a number, and the optional character between groups of three digits
+    /// 
+    /// Thousands separators are only accepted between complete groups, so `1,048,576` is allowed but `10,48` is not
+    /// 
+    /// # Panics
+    /// if both separators are the same, or either is a digit
+    pub const fn digit_separators(self, decimal: char, thousands: Option<char>) -> ParseOptions {
+        assert!(!decimal.is_ascii_digit(), "decimal separator must not be a digit");
+        if let Some(thousands) = thousands {
+            assert!(!thousands.is_ascii_digit(), "thousands separator must not be a digit");
+            assert!(thousands != decimal, "decimal and thousands separators must be different");
+        }
+        ParseOptions { decimal_separator: decimal, thousands_separator: thousands, ..self }
+    }
+
+    /// Sets whether units must be written in their standard case, like `kB`, `KiB`, `MB` and `megabytes`
+    /// 
+    /// The `B` of a byte abbreviation is always case-sensitive, since a lowercase `b` means bits
+    pub const fn case_sensitive(self, case_sensitive: bool) -> ParseOptions {
+        ParseOptions { case_sensitive, ..self }
+    }
+
+    /// Sets whether numbers without a unit are in bits, rather than bytes
+    pub const fn bare_numbers_are_bits(self, bare_numbers_are_bits: bool) -> ParseOptions {
+        ParseOptions { bare_numbers_are_bits, ..self }
+    }
+
+    /// Sets whether decimal prefixes are treated as binary, so `1 kB` means 1024 bytes as in JEDEC memory sizes
+    pub const fn si_as_binary(self, si_as_binary: bool) -> ParseOptions {
+        ParseOptions { si_as_binary, ..self }
+    }
+
+    /// Sets whether numbers that do not come to a whole number of bytes are rejected, rather than kept in bits or rounded
+    /// 
+    /// Each number is checked separately, and this applies to bit units as well, so `12 bit` is rejected, but `16 bit` is not
+    pub const fn reject_fractional_bytes(self, reject_fractional_bytes: bool) -> ParseOptions {
+        ParseOptions { reject_fractional_bytes, ..self }
+    }
+
+    /// Checks whether `c` is a separator that can appear inside a number
+    fn is_separator(&self, c: char) -> bool {
+        c == self.decimal_separator || Some(c) == self.thousands_separator
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::new()
+    }
+}
+
+/// Finds the length of the number at the start of `s`, made of digits and separators that are followed by a digit,
+/// or a prefixed hexadecimal or binary number
+fn number_len(s: &str, options: &ParseOptions) -> usize {
+    if let Some((radix, digits)) = radix_prefix(s) {
+        return 2 + digits.find(|c: char| !c.is_digit(radix)).unwrap_or(digits.len());
+    }
+
+    let mut chars = s.char_indices().peekable();
+    while let Some(&(i, c)) = chars.peek() {
+        let continues = c.is_ascii_digit()
+            || (options.is_separator(c) && s[i + c.len_utf8()..].starts_with(|next: char| next.is_ascii_digit()))
+            //a trailing decimal separator, like `1.`
+            || c == options.decimal_separator && i != 0;
+        if !continues {
+            return i;
+        }
+        chars.next();
+    }
+    s.len()
+}
+
+/// Checks that the thousands separators in `whole` only appear between complete groups of three digits
+fn valid_grouping(whole: &str, thousands: Option<char>) -> bool {
+    let Some(thousands) = thousands else {return true};
+    let mut groups = whole.split(thousands);
+    let first = groups.next().unwrap_or("");
+    let rest_valid = groups.clone().all(|group| group.len() == 3);
+    let grouped = groups.next().is_some();
+
+    !grouped || (1..=3).contains(&first.len()) && rest_valid
+}
+
+/// Splits a `0x` or `0b` prefix from `s` if it is followed by a digit in that base, returning the base and the digits after the prefix
+fn radix_prefix(s: &str) -> Option<(u32, &str)> {
+    let radix = match s.get(..2)? {
+        "0x" | "0X" => 16,
+        "0b" | "0B" => 2,
+        _ => return None,
+    };
+    let digits = &s[2..];
+    digits.starts_with(|c: char| c.is_digit(radix)).then_some((radix, digits))
+}
+
+/// Parses digits with an optional fractional part, like `1.5`, or a whole hexadecimal or binary number like `0x1000`,
+/// which starts at `offset` in the input
+fn parse_decimal(s: &str, options: &ParseOptions, offset: usize) -> Result<Decimal, MemorySizeParseError> {
+    let invalid = MemorySizeParseError::InvalidNumber { offset };
+    if let Some((radix, digits)) = radix_prefix(s) {
+        let mut mantissa: u128 = 0;
+        for c in digits.chars() {
+            let digit = c.to_digit(radix).ok_or(invalid)?;
+            mantissa = mantissa.checked_mul(radix as u128)
+                .and_then(|mantissa| mantissa.checked_add(digit as u128))
+                .ok_or(MemorySizeParseError::Overflow { offset })?;
+        }
+        return Ok(Decimal { mantissa, scale: 0 });
+    }
+
+    let (whole, fraction) = s.split_once(options.decimal_separator).unwrap_or((s, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(invalid);
+    }
+    if fraction.len() > MAX_FRACTION_DIGITS as usize || !valid_grouping(whole, options.thousands_separator) {
+        return Err(invalid);
+    }
+
+    let mut mantissa: u128 = 0;
+    let whole_digits = whole.chars().filter(|&c| Some(c) != options.thousands_separator);
+    for c in whole_digits.chain(fraction.chars()) {
+        let digit = c.to_digit(10).ok_or(invalid)?;
+        mantissa = mantissa.checked_mul(10)
+            .and_then(|mantissa| mantissa.checked_add(digit as u128))
+            .ok_or(MemorySizeParseError::Overflow { offset })?;
+    }
+    Ok(Decimal { mantissa, scale: fraction.len() as u32 })
+}
+
+/// Compares `a` and `b`, ignoring ASCII case unless `case_sensitive` is set
+fn matches(a: &str, b: &str, case_sensitive: bool) -> bool {
+    if case_sensitive {a == b} else {a.eq_ignore_ascii_case(b)}
+}
+
+/// Checks whether `name` is `prefix` followed by `suffix`
+fn is_named(name: &str, prefix: &str, suffix: &str, case_sensitive: bool) -> bool {
+    name.len() == prefix.len() + suffix.len()
+        && name.get(..prefix.len()).is_some_and(|start| matches(start, prefix, case_sensitive))
+        && matches(&name[prefix.len()..], suffix, case_sensitive)
+}
+
+/// Checks whether `unit` is an abbreviation for bits: `b`, `bit` or `bits`
+fn is_bit_abbreviation(unit: &str, case_sensitive: bool) -> bool {
+    unit == "b" || matches(unit, "bit", case_sensitive) || matches(unit, "bits", case_sensitive)
+}
+
+/// The size of a parsed unit
+struct UnitSize {
+    /// Bits in one of the unit
+    bits: u128,
+    /// The multiple of bits that fractions of the unit are rounded to: a byte for byte units, or a bit for bit units
+    rounding: u128,
+}
+
+impl UnitSize {
+    const fn bytes(bytes: u128) -> UnitSize {
+        UnitSize { bits: bytes * BITS_IN_BYTE as u128, rounding: BITS_IN_BYTE as u128 }
+    }
+
+    const fn bits(bits: u128) -> UnitSize {
+        UnitSize { bits, rounding: 1 }
+    }
+}
+
+/// Finds the size of `unit`, or `None` if it is not a known unit
+/// 
+/// An empty unit means bytes, or bits if the options say so
+fn parse_unit(unit: &str, options: &ParseOptions) -> Option<UnitSize> {
+    let case_sensitive = options.case_sensitive;
+    if unit.is_empty() {
+        return Some(if options.bare_numbers_are_bits {UnitSize::bits(1)} else {UnitSize::bytes(1)});
+    }
+    if unit == "B" || matches(unit, "byte", case_sensitive) || matches(unit, "bytes", case_sensitive) {
+        return Some(UnitSize::bytes(1));
+    }
+    if is_bit_abbreviation(unit, case_sensitive) {
+        return Some(UnitSize::bits(1));
+    }
+
+    for (letter, decimal_name, binary_name, power) in PREFIXES {
+        let binary = 1 << (10 * power);
+        let decimal = if options.si_as_binary {binary} else {1000u128.pow(power as u32)};
+
+        let mut chars = unit.chars();
+        let first = chars.next();
+        let rest = chars.as_str();
+        let decimal_letter = if case_sensitive {first == Some(letter)} else {first.is_some_and(|c| c.eq_ignore_ascii_case(&letter))};
+        let binary_letter = if case_sensitive {first == Some(letter.to_ascii_uppercase())} else {decimal_letter};
+
+        if decimal_letter {
+            if rest == "B" {
+                return Some(UnitSize::bytes(decimal));
+            }
+            if is_bit_abbreviation(rest, case_sensitive) {
+                return Some(UnitSize::bits(decimal));
+            }
+        }
+        if binary_letter {
+            if let Some(rest) = rest.strip_prefix('i').or_else(|| rest.strip_prefix('I').filter(|_| !case_sensitive)) {
+                if rest == "B" {
+                    return Some(UnitSize::bytes(binary));
+                }
+                if is_bit_abbreviation(rest, case_sensitive) {
+                    return Some(UnitSize::bits(binary));
+                }
+            }
+        }
+
+        let name = unit.strip_suffix('s').or_else(|| unit.strip_suffix('S').filter(|_| !case_sensitive)).unwrap_or(unit);
+        for (suffix, unit_size) in [("byte", UnitSize::bytes as fn(u128) -> UnitSize), ("bit", UnitSize::bits)] {
+            if is_named(name, decimal_name, suffix, case_sensitive) {
+                return Some(unit_size(decimal));
+            }
+            if is_named(name, binary_name, suffix, case_sensitive) {
+                return Some(unit_size(binary));
+            }
+        }
+    }
+    None
+}
+
+/// Finds the known unit closest to an unrecognised `unit`, ignoring case
+/// 
+/// Only units within two edits, and less than half their own length, are suggested, so short units are not suggested for unrelated words
+fn suggest_unit(unit: &str) -> Option<&'static str> {
+    UNIT_NAMES.into_iter()
+        .map(|name| (name, crate::limit::edit_distance(unit, name)))
+        .filter(|&(name, distance)| distance <= 2 && distance < name.len().div_ceil(2))
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(name, _)| name)
+}
+
+/// Finds the length of the whitespace and optional `+` before a term
+fn skip_term_separator(s: &str) -> usize {
+    let trimmed = s.trim_start();
+    let trimmed = trimmed.strip_prefix('+').map_or(trimmed, str::trim_start);
+    s.len() - trimmed.len()
+}
+
+/// Parses a number and optional unit at the start of `s`, which is at `offset` in the input
+/// 
+/// Returns the size of the term in bits, the length of text it took up, and whether it had a unit
+fn parse_term(s: &str, options: &ParseOptions, offset: usize) -> Result<(u128, usize, bool), MemorySizeParseError> {
+    let (number, after_number) = s.split_at(number_len(s, options));
+    let unit_offset = number.len() + after_number.len() - after_number.trim_start().len();
+    let unit_text = &s[unit_offset..];
+    let unit_text = &unit_text[..unit_text.find(|c: char| !c.is_alphabetic()).unwrap_or(unit_text.len())];
+
+    let number = parse_decimal(number, options, offset)?;
+    let unit = parse_unit(unit_text, options)
+        .ok_or_else(|| MemorySizeParseError::UnknownUnit { offset: offset + unit_offset, suggestion: suggest_unit(unit_text) })?;
+
+    let overflow = MemorySizeParseError::Overflow { offset };
+    let bits = number.mantissa.checked_mul(unit.bits).ok_or(overflow)?;
+    let divisor = 10u128.pow(number.scale);
+    if options.reject_fractional_bytes && bits % (divisor * BITS_IN_BYTE as u128) != 0 {
+        return Err(MemorySizeParseError::FractionalBytes { offset });
+    }
+    let bits = if bits % divisor == 0 {
+        bits / divisor
+    } else {
+        //round half up without adding to `bits`, which can be close to `u128::MAX`
+        let rounding_divisor = divisor * unit.rounding;
+        let remainder = bits % rounding_divisor;
+        let rounded = bits / rounding_divisor + (remainder >= rounding_divisor - remainder) as u128;
+        rounded.checked_mul(unit.rounding).ok_or(overflow)?
+    };
+
+    if unit_text.is_empty() {
+        Ok((bits, number_len(s, options), false))
+    } else {
+        Ok((bits, unit_offset + unit_text.len(), true))
+    }
+}
+
+impl core::str::FromStr for MemorySize {
+    type Err = MemorySizeParseError;
+
+    /// Parses a number followed by an optional unit, like `1.5 GiB`, `512 kB` or `42 bytes`,
+    /// or several of them to be added up, like `1 GiB 512 MiB` or `2GB+256MB`
+    /// 
+    /// Units can be abbreviated with decimal (`kB`, `MB`...) or binary (`KiB`, `MiB`...) prefixes, or written out in full,
+    /// like `kilobytes` or `mebibyte`. Bits can be given in the same ways, like `100 Mbit`, `8 Kib` or `512 bits`.
+    /// The `B` of a byte abbreviation must be uppercase, since a lowercase `b` means bits, but prefixes can be either case.
+    /// A number without a unit is a number of bytes, which is only allowed for the last number.
+    /// Whole numbers can also be written in hexadecimal or binary, like `0x1000 B` or `0b1010`.
+    /// Hexadecimal digits are read greedily, so `0x1B` is 27 bytes, and a space should separate any unit.
+    /// Fractions are kept exactly when they come to a whole number of bits, and are otherwise rounded to the nearest byte, or the nearest bit for bit units
+    /// 
+    /// # Errors
+    /// a `MemorySizeParseError` giving the reason and the byte offset of the problem,
+    /// if `s` is not a number followed by a known unit or the size is too large to represent
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!("1.5 GiB".parse(), Ok(MemorySize::from_bytes(3 << 29)));
+    /// assert_eq!("512 kB".parse(), Ok(MemorySize::from_bytes(512_000)));
+    /// assert_eq!("42 bytes".parse(), Ok(MemorySize::from_bytes(42)));
+    /// assert_eq!("100 Mbit".parse(), Ok(MemorySize::from_bits(100_000_000)));
+    /// assert_eq!("1 GiB 512 MiB".parse(), Ok(MemorySize::from_bytes(3 << 29)));
+    /// assert_eq!("0x200000".parse(), Ok(MemorySize::from_bytes(2 << 20)));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MemorySize::parse_with(s, &ParseOptions::new())
+    }
+}
+
+impl MemorySize {
+    /// Parses `s` in the same way as `from_str`, but with number formatting, units and strictness controlled by `options`
+    /// 
+    /// # Errors
+    /// the same as `from_str`, or `MemorySizeParseError::FractionalBytes` if `options` rejects fractional bytes
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().digit_separators('.', Some(','));
+    /// assert_eq!(MemorySize::parse_with("1,048,576 B", &options), Ok(MemorySize::from_bytes(1 << 20)));
+    ///
+    /// let strict = ParseOptions::new().case_sensitive(true).reject_fractional_bytes(true);
+    /// assert!(MemorySize::parse_with("4 kib", &strict).is_err());
+    /// assert!(MemorySize::parse_with("1.5 B", &strict).is_err());
+    /// ```
+    pub fn parse_with(s: &str, options: &ParseOptions) -> Result<MemorySize, MemorySizeParseError> {
+        if s.trim().is_empty() {
+            return Err(MemorySizeParseError::Empty);
+        }
+
+        let mut total_bits: u128 = 0;
+        let mut offset = 0;
+        loop {
+            let term_start = offset + skip_term_separator(&s[offset..]);
+            let (bits, term_len, has_unit) = parse_term(&s[term_start..], options, term_start)?;
+            offset = term_start + term_len;
+
+            total_bits = total_bits.checked_add(bits)
+                .filter(|&total| total <= u64::MAX as u128)
+                .ok_or(MemorySizeParseError::Overflow { offset: term_start })?;
+
+            let rest = &s[offset..];
+            if rest.trim().is_empty() {
+                return Ok(MemorySize::from_bits(total_bits as u64));
+            }
+            //only the last term can leave out its unit
+            if !has_unit {
+                return Err(MemorySizeParseError::UnknownUnit { offset: offset + rest.len() - rest.trim_start().len(), suggestion: None });
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{MemorySize, BITS_IN_BYTE};
+
+impl MemorySize {
+    /// Returns whether `&self` is a power of two bits
+    /// 
+    /// Since a byte is a power of two bits, this is also whether the number of bytes is a power of two, for sizes of at least a byte
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert!(MemorySize::from_bytes(4096).is_power_of_two());
+    /// assert!(MemorySize::from_bits(2).is_power_of_two());
+    /// assert!(!MemorySize::from_bytes(24).is_power_of_two());
+    /// assert!(!MemorySize::new().is_power_of_two());
+    /// ```
+    pub const fn is_power_of_two(&self) -> bool {
+        self.size_bits.is_power_of_two()
+    }
+
+    /// Calculates the smallest power of two bits that is at least `&self`
+    /// 
+    /// Returns `None` if the result overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(3000).checked_next_power_of_two(), Some(MemorySize::from_bytes(4096)));
+    /// assert_eq!(MemorySize::from_bits(u64::MAX).checked_next_power_of_two(), None);
+    /// ```
+    pub const fn checked_next_power_of_two(&self) -> Option<MemorySize> {
+        match self.size_bits.checked_next_power_of_two() {
+            Some(size_bits) => Some(MemorySize { size_bits }),
+            None => None,
+        }
+    }
+
+    /// Calculates the smallest power of two bits that is at least `&self`
+    /// 
+    /// A size of zero gives one bit
+    /// 
+    /// # Panics
+    /// if the result overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(3000).next_power_of_two(), MemorySize::from_bytes(4096));
+    /// assert_eq!(MemorySize::from_bytes(4096).next_power_of_two(), MemorySize::from_bytes(4096));
+    /// ```
+    pub const fn next_power_of_two(&self) -> MemorySize {
+        self.checked_next_power_of_two().unwrap()
+    }
+
+    /// Calculates the largest power of two bits that is at most `&self`
+    /// 
+    /// # Panics
+    /// if `&self` is zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(3000).prev_power_of_two(), MemorySize::from_bytes(2048));
+    /// assert_eq!(MemorySize::from_bytes(4096).prev_power_of_two(), MemorySize::from_bytes(4096));
+    /// ```
+    pub const fn prev_power_of_two(&self) -> MemorySize {
+        MemorySize { size_bits: 1 << self.ilog2() }
+    }
+
+    /// Calculates the base 2 logarithm of the number of bits, rounded down
+    /// 
+    /// # Panics
+    /// if `&self` is zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(1024).ilog2(), 13);
+    /// assert_eq!(MemorySize::from_bits(5).ilog2(), 2);
+    /// ```
+    pub const fn ilog2(&self) -> u32 {
+        self.size_bits.ilog2()
+    }
+
+    /// Calculates the base 2 logarithm of the number of whole bytes, rounded down
+    /// 
+    /// # Panics
+    /// if `&self` is smaller than a byte
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(1024).ilog2_bytes(), 10);
+    /// assert_eq!(MemorySize::from_bytes(1500).ilog2_bytes(), 10);
+    /// ```
+    pub const fn ilog2_bytes(&self) -> u32 {
+        (self.size_bits / BITS_IN_BYTE).ilog2()
+    }
+
+    /// Calculates the largest power of two bits that divides `&self`, which is the largest alignment that `&self` satisfies
+    /// 
+    /// Zero is aligned to everything, so it gives the largest power of two that fits in a `MemorySize`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(24).natural_alignment(), MemorySize::from_bytes(8));
+    /// assert_eq!(MemorySize::from_bytes(4096 * 3).natural_alignment(), MemorySize::from_bytes(4096));
+    /// assert_eq!(MemorySize::from_bits(12).natural_alignment(), MemorySize::from_bits(4));
+    /// ```
+    pub const fn natural_alignment(&self) -> MemorySize {
+        let shift = if self.size_bits == 0 {u64::BITS - 1} else {self.size_bits.trailing_zeros()};
+        MemorySize { size_bits: 1 << shift }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::MemorySize;
+
+/// Standard RAID levels
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum RaidLevel {
+    /// Striping without redundancy
+    Raid0,
+    /// Every disk holds a full mirror
+    Raid1,
+    /// Striping with one disk's worth of parity
+    Raid5,
+    /// Striping with two disks' worth of parity
+    Raid6,
+    /// Striping over mirrored pairs
+    Raid10,
+}
+
+/// How the space in a RAID array is used, calculated by `RaidLevel::capacity`
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct RaidCapacity {
+    /// Space available for data
+    pub usable: MemorySize,
+    /// Space taken by parity or mirror copies
+    pub redundancy: MemorySize,
+    /// Space left unused because the disks are larger than the smallest disk in the array
+    pub unused: MemorySize,
+    /// Number of disks' worth of data in each stripe
+    pub data_disks: u64,
+}
+
+impl RaidLevel {
+    /// Smallest number of disks that this level can be built from
+    pub const fn min_disks(&self) -> u64 {
+        match self {
+            RaidLevel::Raid0 => 1,
+            RaidLevel::Raid1 => 2,
+            RaidLevel::Raid5 => 3,
+            RaidLevel::Raid6 => 4,
+            RaidLevel::Raid10 => 4,
+        }
+    }
+
+    /// Calculates the capacity of an array of `disks` at this level
+    /// 
+    /// Every disk only contributes as much space as the smallest disk
+    /// 
+    /// Returns `None` if there are too few disks for this level, or an odd number of disks for RAID 10
+    /// 
+    /// # Panics
+    /// if the total size of the disks overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, RaidLevel};
+    ///
+    /// let tb = MemorySize::from_bytes(1_000_000_000_000);
+    /// let array = RaidLevel::Raid5.capacity(&[tb, tb, tb, tb]).unwrap();
+    /// assert_eq!(array.usable, MemorySize::from_bytes(3_000_000_000_000));
+    /// assert_eq!(array.redundancy, tb);
+    /// assert_eq!(array.data_disks, 3);
+    /// ```
+    pub const fn capacity(&self, disks: &[MemorySize]) -> Option<RaidCapacity> {
+        let count = disks.len() as u64;
+        if count < self.min_disks() || (matches!(self, RaidLevel::Raid10) && !count.is_multiple_of(2)) {
+            return None;
+        }
+
+        let mut smallest = disks[0].size_bits();
+        let mut total: u64 = 0;
+        let mut i = 0;
+        while i < disks.len() {
+            let disk = disks[i].size_bits();
+            if disk < smallest {
+                smallest = disk;
+            }
+            total = total.checked_add(disk).unwrap();
+            i += 1;
+        }
+
+        let data_disks = match self {
+            RaidLevel::Raid0 => count,
+            RaidLevel::Raid1 => 1,
+            RaidLevel::Raid5 => count - 1,
+            RaidLevel::Raid6 => count - 2,
+            RaidLevel::Raid10 => count / 2,
+        };
+        let used = smallest * count;//cannot overflow, as it is at most the total
+
+        Some(RaidCapacity {
+            usable: MemorySize::from_bits(smallest * data_disks),
+            redundancy: MemorySize::from_bits(smallest * (count - data_disks)),
+            unused: MemorySize::from_bits(total - used),
+            data_disks,
+        })
+    }
+}
+
+impl RaidCapacity {
+    /// Calculates the amount of data in one full stripe, when each disk holds `chunk` of the stripe
+    /// 
+    /// # Panics
+    /// if the stripe width overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, RaidLevel};
+    ///
+    /// let disk = MemorySize::from_bytes(1 << 40);
+    /// let array = RaidLevel::Raid6.capacity(&[disk; 6]).unwrap();
+    /// assert_eq!(array.stripe_width(MemorySize::from_bytes(512 * 1024)), MemorySize::from_bytes(2 << 20));
+    /// ```
+    pub const fn stripe_width(&self, chunk: MemorySize) -> MemorySize {
+        MemorySize::from_bits(chunk.size_bits().checked_mul(self.data_disks).unwrap())
+    }
+}
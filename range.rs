@@ -0,0 +1,81 @@
+use crate::MemorySize;
+
+/// A half-open range of memory offsets, from `start` up to but not including `end`
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug, Default)]
+pub struct MemoryRange {
+    start: MemorySize,
+    end: MemorySize,
+}
+
+impl MemoryRange {
+    /// Creates a range covering `start..end`
+    /// 
+    /// # Panics
+    /// if `end` is before `start`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemoryRange, MemorySize};
+    ///
+    /// let range = MemoryRange::new(MemorySize::from_bytes(4), MemorySize::from_bytes(10));
+    /// assert_eq!(range.len(), MemorySize::from_bytes(6));
+    /// ```
+    pub const fn new(start: MemorySize, end: MemorySize) -> MemoryRange {
+        assert!(start.size_bits() <= end.size_bits(), "range must not end before it starts");
+        MemoryRange { start, end }
+    }
+
+    /// Creates a range starting at `start` and covering `len`
+    /// 
+    /// # Panics
+    /// if the end of the range overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemoryRange, MemorySize};
+    ///
+    /// let range = MemoryRange::from_start_len(MemorySize::from_bytes(4), MemorySize::from_bytes(6));
+    /// assert_eq!(range.end(), MemorySize::from_bytes(10));
+    /// ```
+    pub const fn from_start_len(start: MemorySize, len: MemorySize) -> MemoryRange {
+        let end = MemorySize::from_bits(start.size_bits().checked_add(len.size_bits()).unwrap());
+        MemoryRange { start, end }
+    }
+
+    /// Returns the first offset in the range
+    pub const fn start(&self) -> MemorySize {
+        self.start
+    }
+
+    /// Returns the offset just past the end of the range
+    pub const fn end(&self) -> MemorySize {
+        self.end
+    }
+
+    /// Calculates the size of the range
+    pub const fn len(&self) -> MemorySize {
+        MemorySize::from_bits(self.end.size_bits() - self.start.size_bits())
+    }
+
+    /// Checks whether the range covers no memory
+    pub const fn is_empty(&self) -> bool {
+        self.start.size_bits() == self.end.size_bits()
+    }
+
+    /// Checks whether `offset` is within the range
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemoryRange, MemorySize};
+    ///
+    /// let range = MemoryRange::new(MemorySize::from_bytes(4), MemorySize::from_bytes(10));
+    /// assert!(range.contains(MemorySize::from_bytes(4)));
+    /// assert!(!range.contains(MemorySize::from_bytes(10)));
+    /// ```
+    pub const fn contains(&self, offset: MemorySize) -> bool {
+        self.start.size_bits() <= offset.size_bits() && offset.size_bits() < self.end.size_bits()
+    }
+}
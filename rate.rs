@@ -0,0 +1,90 @@
+use crate::MemorySize;
+use core::time::Duration;
+
+/// A data transfer rate, stored as an amount of memory per second
+#[derive(PartialEq, PartialOrd, Ord, Eq, Clone, Copy, Hash, Debug, Default)]
+pub struct ByteRate {
+    per_second: MemorySize,
+}
+
+impl ByteRate {
+    /// Creates a rate that transfers `size` every second
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{ByteRate, MemorySize};
+    ///
+    /// let rate = ByteRate::per_second(MemorySize::from_bytes(1024));
+    /// assert_eq!(rate.size_per_second(), MemorySize::from_bytes(1024));
+    /// ```
+    pub const fn per_second(size: MemorySize) -> ByteRate {
+        ByteRate { per_second: size }
+    }
+
+    /// Calculates the rate of transferring `size` in `duration`, rounded down to the nearest bit per second
+    /// 
+    /// # Panics
+    /// if `duration` is zero, or the rate overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{ByteRate, MemorySize};
+    /// use std::time::Duration;
+    ///
+    /// let rate = ByteRate::from_size_and_duration(MemorySize::from_bytes(500), Duration::from_millis(250));
+    /// assert_eq!(rate.size_per_second(), MemorySize::from_bytes(2000));
+    /// ```
+    pub const fn from_size_and_duration(size: MemorySize, duration: Duration) -> ByteRate {
+        let nanos = duration.as_nanos();
+        assert!(nanos != 0, "duration must be non-zero");
+
+        let bits_per_second = size.size_bits() as u128 * 1_000_000_000 / nanos;
+        assert!(bits_per_second <= u64::MAX as u128, "rate overflows");
+        ByteRate::per_second(MemorySize::from_bits(bits_per_second as u64))
+    }
+
+    /// Returns the amount transferred every second
+    pub const fn size_per_second(&self) -> MemorySize {
+        self.per_second
+    }
+
+    /// Calculates how long it takes to transfer `size` at this rate
+    /// 
+    /// Returns `None` if the rate is zero, or the time is too long to fit in a `Duration`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{ByteRate, MemorySize};
+    /// use std::time::Duration;
+    ///
+    /// let rate = ByteRate::per_second(MemorySize::from_bytes(1000));
+    /// assert_eq!(rate.time_for(MemorySize::from_bytes(1500)), Some(Duration::from_millis(1500)));
+    /// assert_eq!(ByteRate::per_second(MemorySize::new()).time_for(MemorySize::from_bytes(1)), None);
+    /// ```
+    pub fn time_for(&self, size: MemorySize) -> Option<Duration> {
+        if self.per_second.size_bits() == 0 {
+            return None;
+        }
+        Duration::try_from_secs_f64(size.size_bits() as f64 / self.per_second.size_bits() as f64).ok()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for ByteRate {
+    /// Formats the rate as an amount per second
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{ByteRate, MemorySize};
+    ///
+    /// let rate = ByteRate::per_second(MemorySize::from_bytes(3 << 20));
+    /// assert_eq!(rate.to_string(), "3.00 MiB/s");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/s", self.per_second)
+    }
+}
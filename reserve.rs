@@ -0,0 +1,37 @@
+use crate::{estimate, MemorySize, MemorySizeError};
+
+/// Reserves capacity in a collection from a memory budget instead of an element count
+pub trait TryReserveSize {
+    /// Tries to reserve capacity for as many additional elements as fit in `additional`
+    /// 
+    /// The budget is rounded down to a whole number of elements
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, TryReserveSize};
+    ///
+    /// let mut data: Vec<u32> = Vec::new();
+    /// data.try_reserve_size(MemorySize::from_bytes(4096)).unwrap();
+    /// assert!(data.capacity() >= 1024);
+    ///
+    /// let too_big = MemorySize::from_bytes(u64::MAX / 8);
+    /// assert!(data.try_reserve_size(too_big).is_err());
+    /// ```
+    fn try_reserve_size(&mut self, additional: MemorySize) -> Result<(), MemorySizeError>;
+}
+
+impl<T> TryReserveSize for Vec<T> {
+    fn try_reserve_size(&mut self, additional: MemorySize) -> Result<(), MemorySizeError> {
+        if size_of::<T>() == 0 {
+            return Ok(());//zero sized types never need to allocate
+        }
+        Ok(self.try_reserve(estimate::vec_capacity_within::<T>(additional))?)
+    }
+}
+
+impl TryReserveSize for String {
+    fn try_reserve_size(&mut self, additional: MemorySize) -> Result<(), MemorySizeError> {
+        Ok(self.try_reserve(estimate::string_capacity_within(additional))?)
+    }
+}
@@ -0,0 +1,28 @@
+/// How to round a result that falls between two representable values
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash, Default)]
+pub enum RoundingMode {
+    /// Round towards zero
+    Floor,
+    /// Round away from zero
+    Ceil,
+    /// Round to the closest value, with halves rounded away from zero
+    #[default]
+    Nearest,
+}
+
+impl RoundingMode {
+    /// Divides `numerator` by `denominator`, rounding the quotient with `self`
+    /// 
+    /// # Panics
+    /// if `denominator` is zero
+    pub(crate) const fn div(self, numerator: u128, denominator: u128) -> u128 {
+        let quotient = numerator / denominator;
+        let remainder = numerator % denominator;
+        let round_up = match self {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => remainder != 0,
+            RoundingMode::Nearest => remainder >= denominator - remainder,
+        };
+        quotient + round_up as u128
+    }
+}
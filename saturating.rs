@@ -0,0 +1,74 @@
+use crate::MemorySize;
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// Wraps a value so that its operators saturate instead of panicking, like `std::num::Saturating`
+///
+/// With a `MemorySize`, addition and multiplication stop at the largest size, and subtraction stops at zero
+///
+/// # Examples
+///
+/// ```
+/// use memory_size::{MemorySize, Saturating};
+///
+/// let mut free = Saturating(MemorySize::from_bytes(100));
+/// free -= Saturating(MemorySize::from_bytes(300));
+/// assert_eq!(free.0, MemorySize::new());
+/// ```
+#[derive(PartialEq, PartialOrd, Ord, Eq, Clone, Copy, Hash, Debug, Default)]
+#[repr(transparent)]
+pub struct Saturating<T>(pub T);
+
+impl Add for Saturating<MemorySize> {
+    type Output = Saturating<MemorySize>;
+
+    fn add(self, rhs: Saturating<MemorySize>) -> Saturating<MemorySize> {
+        Saturating(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl Sub for Saturating<MemorySize> {
+    type Output = Saturating<MemorySize>;
+
+    fn sub(self, rhs: Saturating<MemorySize>) -> Saturating<MemorySize> {
+        Saturating(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl Mul<u64> for Saturating<MemorySize> {
+    type Output = Saturating<MemorySize>;
+
+    fn mul(self, rhs: u64) -> Saturating<MemorySize> {
+        Saturating(self.0.saturating_mul(rhs))
+    }
+}
+
+impl AddAssign for Saturating<MemorySize> {
+    fn add_assign(&mut self, rhs: Saturating<MemorySize>) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Saturating<MemorySize> {
+    fn sub_assign(&mut self, rhs: Saturating<MemorySize>) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign<u64> for Saturating<MemorySize> {
+    fn mul_assign(&mut self, rhs: u64) {
+        *self = *self * rhs;
+    }
+}
+
+impl core::iter::Sum for Saturating<MemorySize> {
+    fn sum<I: Iterator<Item = Saturating<MemorySize>>>(iter: I) -> Self {
+        iter.fold(Saturating(MemorySize::new()), Add::add)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Saturating<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
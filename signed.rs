@@ -0,0 +1,159 @@
+use crate::{format, MemorySize, MemorySizeError, BITS_IN_BYTE};
+use core::fmt;
+use core::ops::Neg;
+use derive_more::{Add, AddAssign, Sub, SubAssign, Sum};
+
+/// A difference between two sizes, which can be negative, like the change in memory usage between snapshots
+///
+/// The representation is guaranteed to be the same as an `i64` holding the number of bits
+#[derive(
+    PartialEq, PartialOrd, Ord, Eq,
+    Clone, Copy, Hash, Debug, Default,
+    Add, Sub, Sum, AddAssign, SubAssign
+)]
+#[repr(transparent)]
+pub struct SignedMemorySize {
+    size_bits: i64,
+}
+
+impl SignedMemorySize {
+    /// Creates a difference of zero bits
+    pub const fn new() -> SignedMemorySize {
+        SignedMemorySize { size_bits: 0 }
+    }
+
+    /// Creates a difference from a number of bits
+    pub const fn from_bits(size_bits: i64) -> SignedMemorySize {
+        SignedMemorySize { size_bits }
+    }
+
+    /// Creates a difference from a number of bytes
+    /// 
+    /// # Panics
+    /// if the number of bits does not fit in an `i64`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::SignedMemorySize;
+    ///
+    /// assert_eq!(SignedMemorySize::from_bytes(-2).size_bits(), -16);
+    /// ```
+    pub const fn from_bytes(size_bytes: i64) -> SignedMemorySize {
+        SignedMemorySize { size_bits: size_bytes.checked_mul(BITS_IN_BYTE as i64).unwrap() }
+    }
+
+    /// Returns the difference in bits
+    pub const fn size_bits(&self) -> i64 {
+        self.size_bits
+    }
+
+    /// Returns whether the difference is below zero
+    pub const fn is_negative(&self) -> bool {
+        self.size_bits < 0
+    }
+
+    /// Returns the size of the difference, ignoring its sign
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, SignedMemorySize};
+    ///
+    /// assert_eq!(SignedMemorySize::from_bytes(-3).unsigned_abs(), MemorySize::from_bytes(3));
+    /// ```
+    pub const fn unsigned_abs(&self) -> MemorySize {
+        MemorySize::from_bits(self.size_bits.unsigned_abs())
+    }
+}
+
+impl MemorySize {
+    /// Calculates `&self` minus `other`, which is negative if `other` is larger
+    /// 
+    /// # Panics
+    /// if the difference does not fit in a `SignedMemorySize`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, SignedMemorySize};
+    ///
+    /// let (before, after) = (MemorySize::from_bytes(100), MemorySize::from_bytes(60));
+    /// assert_eq!(after.signed_diff(before), SignedMemorySize::from_bytes(-40));
+    /// assert_eq!(before.signed_diff(after), SignedMemorySize::from_bytes(40));
+    /// ```
+    pub const fn signed_diff(&self, other: MemorySize) -> SignedMemorySize {
+        let difference = self.size_bits as i128 - other.size_bits as i128;
+        assert!(difference >= i64::MIN as i128 && difference <= i64::MAX as i128, "difference does not fit in a SignedMemorySize");
+        SignedMemorySize { size_bits: difference as i64 }
+    }
+}
+
+impl Neg for SignedMemorySize {
+    type Output = SignedMemorySize;
+
+    /// Flips the sign of the difference
+    /// 
+    /// # Panics
+    /// if the difference is the smallest possible, as its negation does not fit
+    fn neg(self) -> SignedMemorySize {
+        SignedMemorySize { size_bits: self.size_bits.checked_neg().unwrap() }
+    }
+}
+
+impl TryFrom<MemorySize> for SignedMemorySize {
+    type Error = MemorySizeError;
+
+    /// Converts a size into a positive difference
+    /// 
+    /// # Errors
+    /// `MemorySizeError::Overflow` if the size has more bits than fit in an `i64`
+    fn try_from(value: MemorySize) -> Result<Self, Self::Error> {
+        i64::try_from(value.size_bits)
+            .map(SignedMemorySize::from_bits)
+            .map_err(|_| MemorySizeError::Overflow)
+    }
+}
+
+impl TryFrom<SignedMemorySize> for MemorySize {
+    type Error = MemorySizeError;
+
+    /// Converts a difference into a size
+    /// 
+    /// # Errors
+    /// `MemorySizeError::Negative` if the difference is negative
+    fn try_from(value: SignedMemorySize) -> Result<Self, Self::Error> {
+        if value.is_negative() {
+            Err(MemorySizeError::Negative)
+        } else {
+            Ok(value.unsigned_abs())
+        }
+    }
+}
+
+impl fmt::Display for SignedMemorySize {
+    /// Formats the difference like a `MemorySize`, with a `-` before negative differences
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::SignedMemorySize;
+    ///
+    /// assert_eq!(SignedMemorySize::from_bytes(-1536).to_string(), "-1.50 KiB");
+    /// assert_eq!(SignedMemorySize::from_bytes(12).to_string(), "12 B");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        let alternate = f.alternate();
+        format::write_padded(f, |out| {
+            if self.is_negative() {
+                out.write_str("-")?;
+            }
+            match (alternate, precision) {
+                (true, _) => write!(out, "{:#}", self.unsigned_abs()),
+                (false, Some(precision)) => write!(out, "{:.*}", precision, self.unsigned_abs()),
+                (false, None) => write!(out, "{}", self.unsigned_abs()),
+            }
+        })
+    }
+}
@@ -0,0 +1,143 @@
+use crate::MemorySize;
+
+/// The size and alignment of a value, similar to `core::alloc::Layout`
+/// 
+/// Unlike `Layout`, this works at bit granularity and is not limited to `isize::MAX` bytes,
+/// so it can describe bitfields and layouts for other targets
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct SizeAlign {
+    size: MemorySize,
+    align: MemorySize,
+}
+
+impl SizeAlign {
+    /// Creates a `SizeAlign` from a size and alignment
+    /// 
+    /// # Panics
+    /// if `align` is not a power of two number of bits
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, SizeAlign};
+    ///
+    /// let x = SizeAlign::new(MemorySize::from_bytes(6), MemorySize::from_bytes(2));
+    /// assert_eq!(x.size(), MemorySize::from_bytes(6));
+    /// assert_eq!(x.align(), MemorySize::from_bytes(2));
+    /// ```
+    pub const fn new(size: MemorySize, align: MemorySize) -> SizeAlign {
+        assert!(align.size_bits().is_power_of_two(), "alignment must be a power of two");
+        SizeAlign { size, align }
+    }
+
+    /// Creates a `SizeAlign` matching the size and alignment of `T` on the current target
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, SizeAlign};
+    ///
+    /// let x = SizeAlign::of::<u32>();
+    /// assert_eq!(x, SizeAlign::new(MemorySize::from_bytes(4), MemorySize::from_bytes(4)));
+    /// ```
+    pub const fn of<T>() -> SizeAlign {
+        SizeAlign::new(
+            MemorySize::from_bytes(core::mem::size_of::<T>() as u64),
+            MemorySize::from_bytes(core::mem::align_of::<T>() as u64),
+        )
+    }
+
+    /// Returns the size
+    pub const fn size(&self) -> MemorySize {
+        self.size
+    }
+
+    /// Returns the alignment
+    pub const fn align(&self) -> MemorySize {
+        self.align
+    }
+
+    /// Calculates the layout of `&self` followed by `next`, with padding inserted so that `next` is aligned
+    /// 
+    /// Returns the combined layout, and the offset of `next` within it. Trailing padding is not added, see `pad_to_align`
+    /// 
+    /// # Panics
+    /// if the combined size overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, SizeAlign};
+    ///
+    /// // struct { a: u8, b: u32 }
+    /// let (combined, offset) = SizeAlign::of::<u8>().extend(SizeAlign::of::<u32>());
+    /// assert_eq!(offset, MemorySize::from_bytes(4));
+    /// assert_eq!(combined.size(), MemorySize::from_bytes(8));
+    /// assert_eq!(combined.align(), MemorySize::from_bytes(4));
+    /// ```
+    pub const fn extend(&self, next: SizeAlign) -> (SizeAlign, MemorySize) {
+        let offset = self.size.align_up(next.align);
+        let size = MemorySize::from_bits(offset.size_bits().checked_add(next.size.size_bits()).unwrap());
+        let align = if self.align.size_bits() > next.align.size_bits() {self.align} else {next.align};
+
+        (SizeAlign { size, align }, offset)
+    }
+
+    /// Rounds the size up to a multiple of the alignment, so that the layout can be repeated in an array
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, SizeAlign};
+    ///
+    /// let x = SizeAlign::new(MemorySize::from_bytes(5), MemorySize::from_bytes(4));
+    /// assert_eq!(x.pad_to_align().size(), MemorySize::from_bytes(8));
+    /// ```
+    pub const fn pad_to_align(&self) -> SizeAlign {
+        SizeAlign {
+            size: self.size.align_up(self.align),
+            align: self.align,
+        }
+    }
+
+    /// Calculates the layout of `n` copies of `&self` placed one after the other
+    /// 
+    /// Returns the combined layout, and the stride between the start of each copy
+    /// 
+    /// # Panics
+    /// if the combined size overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, SizeAlign};
+    ///
+    /// let x = SizeAlign::new(MemorySize::from_bits(12), MemorySize::from_bits(4));
+    /// let (repeated, stride) = x.repeat(3);
+    /// assert_eq!(stride, MemorySize::from_bits(12));
+    /// assert_eq!(repeated.size(), MemorySize::from_bits(36));
+    /// ```
+    pub const fn repeat(&self, n: u64) -> (SizeAlign, MemorySize) {
+        let stride = self.pad_to_align().size;
+        let size = MemorySize::from_bits(stride.size_bits().checked_mul(n).unwrap());
+
+        (SizeAlign { size, align: self.align }, stride)
+    }
+
+    /// Calculates the layout of an array of `n` elements with the layout `element`
+    /// 
+    /// # Panics
+    /// if the array size overflows
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::{MemorySize, SizeAlign};
+    ///
+    /// let x = SizeAlign::array(SizeAlign::of::<u16>(), 10);
+    /// assert_eq!(x, SizeAlign::of::<[u16; 10]>());
+    /// ```
+    pub const fn array(element: SizeAlign, n: u64) -> SizeAlign {
+        element.repeat(n).0
+    }
+}
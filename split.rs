@@ -0,0 +1,195 @@
+use crate::{MemorySize, BITS_IN_BYTE};
+
+/// Iterator over the parts of an evenly split `MemorySize`, created by `MemorySize::split_evenly`
+#[derive(Clone, Debug)]
+pub struct SplitEvenly {
+    /// Size of the smallest unit that parts are made of, in bits
+    unit_bits: u64,
+    /// Number of units in each smaller part
+    base_units: u64,
+    /// Number of parts that have one extra unit
+    larger_parts: u64,
+    /// Index of the next part to yield
+    next: u64,
+    parts: u64,
+}
+
+/// Iterator over the chunks of a `MemorySize`, created by `MemorySize::chunks`
+#[derive(Clone, Debug)]
+pub struct Chunks {
+    remaining: MemorySize,
+    chunk_size: MemorySize,
+}
+
+impl MemorySize {
+    /// Calculates the granularity that `&self` can be divided at without splitting bytes
+    /// 
+    /// This is a byte if `&self` is a whole number of bytes, otherwise a bit
+    const fn split_unit(&self) -> u64 {
+        if self.size_bits.is_multiple_of(BITS_IN_BYTE) {BITS_IN_BYTE} else {1}
+    }
+
+    /// Splits `&self` into `parts` sizes that sum to `&self`
+    /// 
+    /// If `&self` is a whole number of bytes, every part is a whole number of bytes, and the parts differ by at most one byte.
+    /// Otherwise, the parts differ by at most one bit.
+    /// 
+    /// The larger parts are yielded first
+    /// 
+    /// # Panics
+    /// if `parts` is zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let parts: Vec<_> = MemorySize::from_bytes(10).split_evenly(4).collect();
+    /// assert_eq!(parts, [3, 3, 2, 2].map(MemorySize::from_bytes));
+    /// ```
+    pub const fn split_evenly(&self, parts: u64) -> SplitEvenly {
+        assert!(parts != 0, "cannot split into zero parts");
+        let unit_bits = self.split_unit();
+        let units = self.size_bits / unit_bits;
+
+        SplitEvenly {
+            unit_bits,
+            base_units: units / parts,
+            larger_parts: units % parts,
+            next: 0,
+            parts,
+        }
+    }
+
+    /// Splits `&self` into the first `at`, and the rest
+    /// 
+    /// # Panics
+    /// if `at` is larger than `&self`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let (head, tail) = MemorySize::from_bytes(100).split_at(MemorySize::from_bytes(30));
+    /// assert_eq!((head, tail), (MemorySize::from_bytes(30), MemorySize::from_bytes(70)));
+    /// ```
+    pub const fn split_at(&self, at: MemorySize) -> (MemorySize, MemorySize) {
+        assert!(at.size_bits <= self.size_bits, "split point is past the end of the size");
+        (at, MemorySize::from_bits(self.size_bits - at.size_bits))
+    }
+
+    /// Splits `&self` into chunks of `chunk_size`, where the last chunk is smaller if `chunk_size` does not divide `&self` evenly
+    /// 
+    /// # Panics
+    /// if `chunk_size` is zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let chunks: Vec<_> = MemorySize::from_bytes(10).chunks(MemorySize::from_bytes(4)).collect();
+    /// assert_eq!(chunks, [4, 4, 2].map(MemorySize::from_bytes));
+    /// ```
+    pub const fn chunks(&self, chunk_size: MemorySize) -> Chunks {
+        assert!(chunk_size.size_bits != 0, "chunk size must not be zero");
+        Chunks { remaining: *self, chunk_size }
+    }
+
+    /// Splits `&self` into parts proportional to `weights`, that sum to `&self`
+    /// 
+    /// Like `split_evenly`, parts are whole bytes when `&self` is a whole number of bytes.
+    /// Units left over from rounding down are handed out using the largest remainder method,
+    /// with ties going to the earlier weight
+    /// 
+    /// # Panics
+    /// if `weights` is empty or every weight is zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let budget = MemorySize::from_bytes(100);
+    /// let parts = budget.split_by_weights(&[1, 1, 1]);
+    /// assert_eq!(parts, [34, 33, 33].map(MemorySize::from_bytes));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn split_by_weights(&self, weights: &[u64]) -> Vec<MemorySize> {
+        let total_weight: u128 = weights.iter().map(|&w| w as u128).sum();
+        assert!(total_weight != 0, "weights must not all be zero");
+
+        let unit_bits = self.split_unit();
+        let units = (self.size_bits / unit_bits) as u128;
+
+        //exact share of each part, split into whole units and the remainder
+        let shares: Vec<(u128, u128)> = weights.iter()
+            .map(|&w| {
+                let exact = units * w as u128;
+                (exact / total_weight, exact % total_weight)
+            })
+            .collect();
+
+        let allocated: u128 = shares.iter().map(|share| share.0).sum();
+        let leftover = (units - allocated) as usize;
+
+        let mut by_remainder: Vec<usize> = (0..shares.len()).collect();
+        by_remainder.sort_by(|&a, &b| shares[b].1.cmp(&shares[a].1).then(a.cmp(&b)));
+
+        let mut part_units: Vec<u128> = shares.iter().map(|share| share.0).collect();
+        for &i in &by_remainder[..leftover] {
+            part_units[i] += 1;
+        }
+
+        part_units.into_iter()
+            .map(|x| MemorySize::from_bits(x as u64 * unit_bits))
+            .collect()
+    }
+}
+
+impl Iterator for SplitEvenly {
+    type Item = MemorySize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.parts {
+            return None;
+        }
+        let units = if self.next < self.larger_parts {self.base_units + 1} else {self.base_units};
+        self.next += 1;
+
+        Some(MemorySize::from_bits(units * self.unit_bits))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.parts - self.next).ok();
+        (remaining.unwrap_or(usize::MAX), remaining)
+    }
+}
+
+impl ExactSizeIterator for SplitEvenly {}
+
+impl core::iter::FusedIterator for SplitEvenly {}
+
+impl Iterator for Chunks {
+    type Item = MemorySize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.size_bits == 0 {
+            return None;
+        }
+        let chunk = if self.remaining < self.chunk_size {self.remaining} else {self.chunk_size};
+        self.remaining = MemorySize::from_bits(self.remaining.size_bits - chunk.size_bits);
+
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.remaining.div_ceil(self.chunk_size)).ok();
+        (remaining.unwrap_or(usize::MAX), remaining)
+    }
+}
+
+impl ExactSizeIterator for Chunks {}
+
+impl core::iter::FusedIterator for Chunks {}
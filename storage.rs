@@ -0,0 +1,76 @@
+use crate::MemorySize;
+
+impl MemorySize {
+    /// The size of a legacy disk sector
+    pub const SECTOR_512: MemorySize = MemorySize::from_bytes(512);
+    /// The size of an Advanced Format disk sector
+    pub const SECTOR_4K: MemorySize = MemorySize::from_bytes(4096);
+
+    /// Constructs a `MemorySize` from a number of sectors of size `sector_size`
+    /// 
+    /// # Panics
+    /// if the size would overflow
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let size = MemorySize::from_sectors(2048, MemorySize::SECTOR_512);
+    /// assert_eq!(size, MemorySize::from_bytes(1024 * 1024));
+    /// ```
+    pub const fn from_sectors(sectors: u64, sector_size: MemorySize) -> MemorySize {
+        MemorySize::from_bits(sectors.checked_mul(sector_size.size_bits).unwrap())
+    }
+
+    /// Calculates the number of sectors of size `sector_size` needed to hold `&self`
+    /// 
+    /// # Panics
+    /// if `sector_size` is zero
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(513).to_sectors_ceil(MemorySize::SECTOR_512), 2);
+    /// ```
+    pub const fn to_sectors_ceil(&self, sector_size: MemorySize) -> u64 {
+        self.div_ceil(sector_size)
+    }
+
+    /// Calculates the space that `&self` takes on disk when allocated in clusters of `cluster_size`
+    /// 
+    /// # Panics
+    /// if `cluster_size` is zero, or the size would overflow
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let cluster = MemorySize::from_bytes(4096);
+    /// assert_eq!(MemorySize::from_bytes(5000).round_to_clusters(cluster), MemorySize::from_bytes(8192));
+    /// assert_eq!(MemorySize::new().round_to_clusters(cluster), MemorySize::new());
+    /// ```
+    pub const fn round_to_clusters(&self, cluster_size: MemorySize) -> MemorySize {
+        MemorySize::from_sectors(self.to_sectors_ceil(cluster_size), cluster_size)
+    }
+
+    /// Calculates the space wasted at the end of the last cluster when `&self` is allocated in clusters of `cluster_size`
+    /// 
+    /// # Panics
+    /// if `cluster_size` is zero, or the rounded size would overflow
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let cluster = MemorySize::from_bytes(4096);
+    /// assert_eq!(MemorySize::from_bytes(5000).cluster_slack(cluster), MemorySize::from_bytes(3192));
+    /// ```
+    pub const fn cluster_slack(&self, cluster_size: MemorySize) -> MemorySize {
+        MemorySize::from_bits(self.round_to_clusters(cluster_size).size_bits - self.size_bits)
+    }
+}
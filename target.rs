@@ -0,0 +1,106 @@
+use crate::{MemorySize, SizeAlign};
+
+/// A primitive type whose layout differs between targets
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum Primitive {
+    /// `i8` or `u8`
+    I8,
+    /// `i16` or `u16`
+    I16,
+    /// `i32` or `u32`
+    I32,
+    /// `i64` or `u64`
+    I64,
+    /// `i128` or `u128`
+    I128,
+    /// `f32`
+    F32,
+    /// `f64`
+    F64,
+    /// Pointers, `usize` and `isize`
+    Pointer,
+}
+
+/// The sizes and alignments of primitive types on a target, used to compute layouts for another architecture
+/// 
+/// # Examples
+///
+/// ```
+/// use memory_size::{MemorySize, Primitive, SizeAlign, TargetLayout};
+///
+/// let wasm = TargetLayout::from_triple("wasm32-unknown-unknown").unwrap();
+/// let (node, _) = wasm.primitive(Primitive::Pointer).extend(wasm.primitive(Primitive::I64));
+/// assert_eq!(node.pad_to_align().size(), MemorySize::from_bytes(16));
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub struct TargetLayout {
+    i64_align: MemorySize,
+    i128_align: MemorySize,
+    f64_align: MemorySize,
+    pointer_size: MemorySize,
+}
+
+impl TargetLayout {
+    /// x86_64 with the System V ABI, used by Linux, macOS and the BSDs
+    pub const X86_64_SYSV: TargetLayout = TargetLayout {
+        i64_align: MemorySize::from_bytes(8),
+        i128_align: MemorySize::from_bytes(16),
+        f64_align: MemorySize::from_bytes(8),
+        pointer_size: MemorySize::from_bytes(8),
+    };
+
+    /// 64-bit ARM with the AAPCS64 ABI
+    pub const AARCH64_AAPCS: TargetLayout = TargetLayout {
+        i64_align: MemorySize::from_bytes(8),
+        i128_align: MemorySize::from_bytes(16),
+        f64_align: MemorySize::from_bytes(8),
+        pointer_size: MemorySize::from_bytes(8),
+    };
+
+    /// 32-bit WebAssembly
+    pub const WASM32: TargetLayout = TargetLayout {
+        i64_align: MemorySize::from_bytes(8),
+        i128_align: MemorySize::from_bytes(16),
+        f64_align: MemorySize::from_bytes(8),
+        pointer_size: MemorySize::from_bytes(4),
+    };
+
+    /// Finds the preset for a target triple, like `x86_64-unknown-linux-gnu`, by its architecture
+    /// 
+    /// Returns `None` for architectures without a preset
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::TargetLayout;
+    ///
+    /// assert_eq!(TargetLayout::from_triple("aarch64-apple-darwin"), Some(TargetLayout::AARCH64_AAPCS));
+    /// assert_eq!(TargetLayout::from_triple("mips-unknown-linux-gnu"), None);
+    /// ```
+    pub fn from_triple(triple: &str) -> Option<TargetLayout> {
+        match triple.split('-').next()? {
+            "x86_64" => Some(TargetLayout::X86_64_SYSV),
+            "aarch64" | "arm64" => Some(TargetLayout::AARCH64_AAPCS),
+            "wasm32" => Some(TargetLayout::WASM32),
+            _ => None,
+        }
+    }
+
+    /// Returns the size and alignment of `primitive` on this target
+    pub const fn primitive(&self, primitive: Primitive) -> SizeAlign {
+        match primitive {
+            Primitive::I8 => natural(1),
+            Primitive::I16 => natural(2),
+            Primitive::I32 | Primitive::F32 => natural(4),
+            Primitive::I64 => SizeAlign::new(MemorySize::from_bytes(8), self.i64_align),
+            Primitive::I128 => SizeAlign::new(MemorySize::from_bytes(16), self.i128_align),
+            Primitive::F64 => SizeAlign::new(MemorySize::from_bytes(8), self.f64_align),
+            Primitive::Pointer => SizeAlign::new(self.pointer_size, self.pointer_size),
+        }
+    }
+}
+
+/// The layout of a primitive that is aligned to its own size on every target
+const fn natural(bytes: u64) -> SizeAlign {
+    SizeAlign::new(MemorySize::from_bytes(bytes), MemorySize::from_bytes(bytes))
+}
@@ -1,4 +1,5 @@
 #![cfg(test)]
+#![allow(clippy::useless_vec, clippy::manual_div_ceil, clippy::redundant_closure)]
 
 use crate::MemorySize;
 
@@ -241,13 +242,13 @@ fn subtract_assign_panic() {
 #[test]
 fn display_format() {
     let layout = MemorySize::from_bytes(1024); // 1 KB
-    assert_eq!(format!("{}", layout), "8192bit");
+    assert_eq!(format!("{}", layout), "1.00 KiB");
 
     let layout = MemorySize::from_bits(9);
-    assert_eq!(layout.to_string(), "9bit");
+    assert_eq!(layout.to_string(), "9 bit");
 
     let layout = MemorySize::from_bytes(0); // 0 Bytes
-    assert_eq!(format!("{}", layout), "0bit");
+    assert_eq!(format!("{}", layout), "0 B");
 }
 
 #[test]
@@ -311,4 +312,2227 @@ fn test_round_up_byte() {
     assert_eq!(MemorySize::new().round_up_byte(), MemorySize::new());
 
     assert_eq!(MemorySize::from_bytes(3).round_up_byte(), MemorySize::from_bytes(3));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_page_table_overhead() {
+    let page_4k = MemorySize::from_bytes(4096);
+    let page_2m = MemorySize::from_bytes(2 << 20);
+
+    assert_eq!(MemorySize::new().page_table_overhead(page_4k, 4), MemorySize::new());
+
+    //a single page still needs one table per level
+    assert_eq!(MemorySize::from_bytes(1).page_table_overhead(page_4k, 4), MemorySize::from_bytes(4 * 4096));
+
+    //1 GiB of huge pages only needs one page directory, plus the two tables above it
+    let gib = MemorySize::from_bytes(1 << 30);
+    assert_eq!(gib.page_table_overhead(page_2m, 3), MemorySize::from_bytes(3 * 4096));
+    assert_eq!(gib.page_table_overhead(page_4k, 0), MemorySize::new());
+}
+
+#[test]
+#[should_panic]
+fn page_table_overhead_overflow() {
+    let _ = MemorySize::from_bits(u64::MAX).page_table_overhead(MemorySize::from_bits(1), 4);
+}
+
+#[test]
+fn mem_size_of_std_types() {
+    use crate::MemSizeOf;
+
+    assert_eq!(5u32.heap_size_of(), MemorySize::new());
+    assert_eq!(5u32.mem_size_of(), MemorySize::from_bytes(4));
+
+    let text = String::with_capacity(10);
+    assert_eq!(text.heap_size_of(), MemorySize::from_bytes(10));
+
+    let nested: Vec<String> = vec![String::with_capacity(3), String::with_capacity(5)];
+    let outer = MemorySize::from_bytes((nested.capacity() * std::mem::size_of::<String>()) as u64);
+    assert_eq!(nested.heap_size_of(), outer + MemorySize::from_bytes(8));
+
+    let boxed = Box::new(7u64);
+    assert_eq!(boxed.heap_size_of(), MemorySize::from_bytes(8));
+
+    assert_eq!(None::<String>.heap_size_of(), MemorySize::new());
+    assert_eq!(Some(String::with_capacity(4)).heap_size_of(), MemorySize::from_bytes(4));
+}
+
+#[test]
+fn mem_size_of_derive() {
+    use crate::MemSizeOf;
+    #[cfg(not(feature = "derive"))]
+    use memory_size_derive::MemSizeOf;
+
+    #[derive(MemSizeOf)]
+    struct Named {
+        id: u32,
+        name: String,
+    }
+
+    #[derive(MemSizeOf)]
+    struct Wrapper<T>(Vec<T>, u8);
+
+    #[derive(MemSizeOf)]
+    #[allow(dead_code)]
+    enum Shape {
+        Empty,
+        Label(String),
+        Points { xs: Vec<u16> },
+    }
+
+    #[derive(MemSizeOf)]
+    enum Never {}
+
+    fn implements_mem_size_of<T: MemSizeOf>() {}
+    implements_mem_size_of::<Never>();
+
+    let named = Named { id: 1, name: String::with_capacity(12) };
+    assert_eq!(named.heap_size_of(), MemorySize::from_bytes(12));
+    assert_eq!(named.mem_size_of(), MemorySize::from_bytes(12 + std::mem::size_of::<Named>() as u64));
+    assert_eq!(named.id, 1);
+
+    let wrapper = Wrapper(Vec::<u64>::with_capacity(4), 0);
+    assert_eq!(wrapper.heap_size_of(), MemorySize::from_bytes(32));
+
+    assert_eq!(Shape::Empty.heap_size_of(), MemorySize::new());
+    assert_eq!(Shape::Label(String::with_capacity(2)).heap_size_of(), MemorySize::from_bytes(2));
+    assert_eq!(Shape::Points { xs: Vec::with_capacity(3) }.heap_size_of(), MemorySize::from_bytes(6));
+}
+
+#[test]
+fn collection_estimates() {
+    use crate::estimate;
+
+    assert_eq!(estimate::vec_heap_size::<u64>(0), MemorySize::new());
+    assert_eq!(estimate::vec_heap_size::<(u8, u32)>(10), MemorySize::from_bytes(80));
+    assert_eq!(estimate::string_heap_size(3), MemorySize::from_bytes(3));
+
+    //small tables round up to 4 or 8 buckets
+    assert_eq!(estimate::hash_map_heap_size::<u32, u32>(3), MemorySize::from_bytes(4 * 8 + 4 + 16));
+    assert_eq!(estimate::hash_map_heap_size::<u32, u32>(7), MemorySize::from_bytes(8 * 8 + 8 + 16));
+    //the 7/8 load factor forces a larger table
+    assert_eq!(estimate::hash_map_heap_size::<u8, u8>(8), MemorySize::from_bytes(16 * 2 + 16 + 16));
+
+    //the estimate should cover what the real map reports as its capacity
+    let map: std::collections::HashMap<u64, u64> = std::collections::HashMap::with_capacity(100);
+    assert_eq!(
+        estimate::hash_map_heap_size::<u64, u64>(map.capacity()),
+        estimate::hash_map_heap_size::<u64, u64>(100)
+    );
+}
+
+#[test]
+fn collection_capacity_within() {
+    use crate::estimate;
+
+    assert_eq!(estimate::vec_capacity_within::<u64>(MemorySize::from_bytes(7)), 0);
+    assert_eq!(estimate::vec_capacity_within::<u64>(MemorySize::from_bytes(8)), 1);
+    assert_eq!(estimate::string_capacity_within(MemorySize::from_bits(7)), 0);
+
+    //too small for even the smallest table
+    assert_eq!(estimate::hash_map_capacity_within::<u32, u32>(MemorySize::from_bytes(10)), 0);
+
+    for budget_bytes in [52, 100, 1000, 123_456, 1 << 30] {
+        let budget = MemorySize::from_bytes(budget_bytes);
+        let capacity = estimate::hash_map_capacity_within::<u32, u32>(budget);
+        assert!(estimate::hash_map_heap_size::<u32, u32>(capacity) <= budget);
+        assert!(estimate::hash_map_heap_size::<u32, u32>(capacity + 1) > budget);
+    }
+}
+
+#[test]
+fn test_next_grow() {
+    use crate::GrowthPolicy;
+
+    //repeated growth from empty
+    let mut size = MemorySize::new();
+    for expected in [1, 2, 4, 8, 16] {
+        size = size.next_grow(GrowthPolicy::Double).unwrap();
+        assert_eq!(size, MemorySize::from_bytes(expected));
+    }
+
+    let mut size = MemorySize::new();
+    for expected in [1, 2, 3, 5, 8, 12] {
+        size = size.next_grow(GrowthPolicy::OneAndHalf).unwrap();
+        assert_eq!(size, MemorySize::from_bytes(expected));
+    }
+
+    //partial bytes are rounded up
+    assert_eq!(MemorySize::from_bits(9).next_grow(GrowthPolicy::Double), Some(MemorySize::from_bytes(3)));
+    //a zero step still makes progress
+    assert_eq!(MemorySize::from_bytes(3).next_grow(GrowthPolicy::FixedStep(MemorySize::new())), Some(MemorySize::from_bytes(4)));
+
+    let huge = MemorySize::from_bits(u64::MAX - 4);
+    assert_eq!(huge.next_grow(GrowthPolicy::OneAndHalf), None);
+    assert_eq!(huge.next_grow(GrowthPolicy::FixedStep(MemorySize::from_bits(1))), None);
+}
+
+#[test]
+fn test_try_reserve_size() {
+    use crate::{MemorySizeError, TryReserveSize};
+
+    let mut data: Vec<u64> = Vec::new();
+    data.try_reserve_size(MemorySize::from_bytes(7)).unwrap();//less than one element
+    data.try_reserve_size(MemorySize::from_bytes(80)).unwrap();
+    assert!(data.capacity() >= 10);
+
+    let mut text = String::new();
+    text.try_reserve_size(MemorySize::from_bits(100)).unwrap();
+    assert!(text.capacity() >= 12);
+    let err = text.try_reserve_size(MemorySize::from_bits(u64::MAX)).unwrap_err();
+    assert!(matches!(err, MemorySizeError::Reserve(_)));
+
+    let mut zst: Vec<()> = vec![(); 5];
+    zst.try_reserve_size(MemorySize::from_bits(u64::MAX)).unwrap();
+}
+
+#[test]
+fn test_split_evenly() {
+    let total = MemorySize::from_bytes(1000);
+    let parts: Vec<_> = total.split_evenly(7).collect();
+    assert_eq!(parts.len(), 7);
+    assert_eq!(parts.iter().copied().sum::<MemorySize>(), total);
+    assert!(parts.iter().all(|x| x.size_bits() % 8 == 0));
+    assert_eq!(parts[0], MemorySize::from_bytes(143));
+    assert_eq!(parts[6], MemorySize::from_bytes(142));
+
+    //not a whole number of bytes, so split by bits
+    let odd: Vec<_> = MemorySize::from_bits(11).split_evenly(2).collect();
+    assert_eq!(odd, [MemorySize::from_bits(6), MemorySize::from_bits(5)]);
+
+    //more parts than units
+    let sparse: Vec<_> = MemorySize::from_bytes(2).split_evenly(3).collect();
+    assert_eq!(sparse, [1, 1, 0].map(MemorySize::from_bytes));
+
+    assert_eq!(MemorySize::new().split_evenly(3).len(), 3);
+}
+
+#[test]
+#[should_panic]
+fn split_evenly_zero_parts() {
+    let _ = MemorySize::from_bytes(10).split_evenly(0);
+}
+
+#[test]
+fn test_split_by_weights() {
+    let budget = MemorySize::from_bytes(1 << 20);
+    let parts = budget.split_by_weights(&[70, 20, 10]);
+    assert_eq!(parts.iter().copied().sum::<MemorySize>(), budget);
+    assert_eq!(parts[2], MemorySize::from_bytes(104858));//10% of 1048576 rounded to nearest
+
+    //zero weights get nothing
+    let parts = MemorySize::from_bytes(7).split_by_weights(&[0, 2, 5]);
+    assert_eq!(parts, [0, 2, 5].map(MemorySize::from_bytes));
+
+    //largest remainders win the leftover units
+    let parts = MemorySize::from_bits(10).split_by_weights(&[1, 2, 3]);
+    assert_eq!(parts, [2, 3, 5].map(MemorySize::from_bits));
+
+    //huge sizes and weights do not overflow
+    let parts = MemorySize::from_bits(u64::MAX).split_by_weights(&[u64::MAX, u64::MAX]);
+    assert_eq!(parts.iter().copied().sum::<MemorySize>(), MemorySize::from_bits(u64::MAX));
+}
+
+#[test]
+#[should_panic]
+fn split_by_weights_all_zero() {
+    let _ = MemorySize::from_bytes(10).split_by_weights(&[0, 0]);
+}
+
+#[test]
+fn test_exact_division() {
+    let zero = MemorySize::new();
+    let byte = MemorySize::from_bytes(1);
+
+    assert!(zero.divides_evenly(byte));
+    assert!(!byte.divides_evenly(zero));
+    assert!(!zero.divides_evenly(zero));
+    assert!(!byte.divides_evenly(MemorySize::from_bits(3)));
+
+    assert_eq!(zero.checked_exact_div(byte), Some(0));
+    assert_eq!(byte.checked_exact_div(zero), None);
+    assert_eq!(byte.checked_exact_div(MemorySize::from_bits(1)), Some(8));
+    assert_eq!(MemorySize::from_bits(u64::MAX).checked_exact_div(MemorySize::from_bits(u64::MAX)), Some(1));
+}
+
+#[test]
+fn test_align_up_all() {
+    let size = MemorySize::from_bytes(1000);
+    let page = MemorySize::from_bytes(4096);
+    let simd = MemorySize::from_bytes(64);
+    let dma = MemorySize::from_bytes(48);
+
+    assert_eq!(size.align_up_all(&[]), size);
+    assert_eq!(size.align_up_all(&[MemorySize::new()]), size);
+    assert_eq!(size.align_up_all(&[simd, page]), page);
+    //lcm(4096, 48) = 12288
+    assert_eq!(size.align_up_all(&[simd, dma, page]), MemorySize::from_bytes(12288));
+    assert_eq!(MemorySize::from_bits(3).align_up_all(&[MemorySize::from_bits(2), MemorySize::from_bits(3)]), MemorySize::from_bits(6));
+}
+
+#[test]
+#[should_panic]
+fn align_up_all_overflow() {
+    let a = MemorySize::from_bits(u64::MAX);
+    let b = MemorySize::from_bits(u64::MAX - 1);
+    let _ = MemorySize::from_bytes(1).align_up_all(&[a, b]);
+}
+
+#[test]
+fn size_align_struct_layout() {
+    use crate::SizeAlign;
+
+    #[allow(dead_code)]
+    #[repr(C)]
+    struct Example {
+        a: u8,
+        b: u64,
+        c: u16,
+    }
+
+    let (layout, offset_b) = SizeAlign::of::<u8>().extend(SizeAlign::of::<u64>());
+    let (layout, offset_c) = layout.extend(SizeAlign::of::<u16>());
+    assert_eq!(offset_b, MemorySize::from_bytes(std::mem::offset_of!(Example, b) as u64));
+    assert_eq!(offset_c, MemorySize::from_bytes(std::mem::offset_of!(Example, c) as u64));
+    assert_eq!(layout.pad_to_align(), SizeAlign::of::<Example>());
+
+    //bit granularity
+    let flag = SizeAlign::new(MemorySize::from_bits(1), MemorySize::from_bits(1));
+    let nibble = SizeAlign::new(MemorySize::from_bits(4), MemorySize::from_bits(4));
+    let (bits, offset) = flag.extend(nibble);
+    assert_eq!(offset, MemorySize::from_bits(4));
+    assert_eq!(bits.size(), MemorySize::from_bits(8));
+
+    let (empty, stride) = SizeAlign::of::<u32>().repeat(0);
+    assert_eq!(empty.size(), MemorySize::new());
+    assert_eq!(stride, MemorySize::from_bytes(4));
+}
+
+#[test]
+#[should_panic]
+fn size_align_invalid_alignment() {
+    let _ = crate::SizeAlign::new(MemorySize::from_bytes(4), MemorySize::from_bytes(3));
+}
+
+#[test]
+fn wasm_pages() {
+    assert_eq!(MemorySize::from_wasm_pages(0), MemorySize::new());
+    assert_eq!(MemorySize::from_wasm_pages(1), MemorySize::WASM_PAGE);
+    assert_eq!(MemorySize::new().to_wasm_pages_ceil(), 0);
+    assert_eq!(MemorySize::from_bits(1).to_wasm_pages_ceil(), 1);
+    assert_eq!(MemorySize::from_wasm_pages(7).to_wasm_pages_ceil(), 7);
+
+    assert_eq!(MemorySize::from_wasm_pages(4).wasm_grow_delta(4), 0);
+    assert_eq!(MemorySize::from_wasm_pages(4).wasm_grow_delta(0), 4);
+}
+
+#[test]
+fn gpu_layouts() {
+    use crate::{GpuLayout, SizeAlign};
+
+    let vec2 = SizeAlign::new(MemorySize::from_bytes(8), MemorySize::from_bytes(8));
+    let mat4 = SizeAlign::new(MemorySize::from_bytes(64), MemorySize::from_bytes(16));
+
+    assert_eq!(GpuLayout::Std140.array_stride(vec2), MemorySize::from_bytes(16));
+    assert_eq!(GpuLayout::Std430.array_stride(vec2), MemorySize::from_bytes(8));
+    assert_eq!(GpuLayout::Std140.array_stride(mat4), MemorySize::from_bytes(64));
+    assert_eq!(GpuLayout::Std140.element_align(MemorySize::from_bytes(32)), MemorySize::from_bytes(32));
+
+    let stride = MemorySize::from_bytes(256).gpu_dynamic_offset_stride(MemorySize::GPU_BUFFER_OFFSET_ALIGNMENT);
+    assert_eq!(stride, MemorySize::from_bytes(256));
+    assert_eq!(MemorySize::from_bytes(257).gpu_dynamic_offset_stride(MemorySize::from_bytes(64)), MemorySize::from_bytes(320));
+}
+
+#[test]
+fn disk_sectors() {
+    let disk = MemorySize::from_sectors(1_000_000, MemorySize::SECTOR_4K);
+    assert_eq!(disk.to_sectors_ceil(MemorySize::SECTOR_4K), 1_000_000);
+    assert_eq!(disk.to_sectors_ceil(MemorySize::SECTOR_512), 8_000_000);
+
+    assert_eq!(MemorySize::new().to_sectors_ceil(MemorySize::SECTOR_512), 0);
+    assert_eq!(MemorySize::from_bits(1).to_sectors_ceil(MemorySize::SECTOR_512), 1);
+
+    let cluster = MemorySize::from_bytes(32 * 1024);
+    assert_eq!(cluster.round_to_clusters(cluster), cluster);
+    assert_eq!(cluster.cluster_slack(cluster), MemorySize::new());
+    assert_eq!(MemorySize::from_bytes(1).cluster_slack(cluster), MemorySize::from_bytes(32 * 1024 - 1));
+}
+
+#[test]
+#[should_panic]
+fn sectors_zero_size() {
+    let _ = MemorySize::from_bytes(1).to_sectors_ceil(MemorySize::new());
+}
+
+#[test]
+fn encoded_sizes() {
+    use crate::Encoding;
+
+    //RFC 4648 test vectors: "", "f", "fo", "foo", "foob", "fooba", "foobar"
+    let padded = [0, 4, 4, 4, 8, 8, 8];
+    let unpadded = [0, 2, 3, 4, 6, 7, 8];
+    for (len, (padded, unpadded)) in padded.into_iter().zip(unpadded).enumerate() {
+        let input = MemorySize::from_bytes(len as u64);
+        assert_eq!(Encoding::Base64.encoded_size(input), MemorySize::from_bytes(padded));
+        assert_eq!(Encoding::Base64Unpadded.encoded_size(input), MemorySize::from_bytes(unpadded));
+
+        //decoding unpadded data is exact
+        assert_eq!(Encoding::Base64Unpadded.decoded_size(MemorySize::from_bytes(unpadded)), input);
+        assert!(Encoding::Base64.decoded_size(MemorySize::from_bytes(padded)) >= input);
+    }
+
+    for encoding in [Encoding::Hex, Encoding::Percent] {
+        let input = MemorySize::from_bytes(1234);
+        assert!(encoding.decoded_size(encoding.encoded_size(input)) >= input);
+    }
+    assert_eq!(Encoding::Hex.decoded_size(MemorySize::from_bytes(5)), MemorySize::from_bytes(2));
+}
+
+#[test]
+#[should_panic]
+fn encoded_size_partial_byte() {
+    let _ = crate::Encoding::Hex.encoded_size(MemorySize::from_bits(4));
+}
+
+#[test]
+fn compression_ratio() {
+    use crate::CompressionRatio;
+
+    let ratio = CompressionRatio::new(MemorySize::from_bytes(100), MemorySize::from_bytes(100));
+    assert_eq!(ratio.ratio(), 1.0);
+    assert_eq!(ratio.to_string(), "1.0:1, saved 0%");
+
+    //compression made it bigger
+    let ratio = CompressionRatio::new(MemorySize::from_bytes(100), MemorySize::from_bytes(110));
+    assert_eq!(ratio.to_string(), "0.9:1, saved -10%");
+    assert_eq!(ratio.apply(MemorySize::from_bytes(10)), MemorySize::from_bytes(11));
+
+    //estimates are rounded up
+    let ratio = CompressionRatio::new(MemorySize::from_bits(3), MemorySize::from_bits(1));
+    assert_eq!(ratio.apply(MemorySize::from_bits(4)), MemorySize::from_bits(2));
+    assert_eq!(ratio.apply(MemorySize::from_bits(u64::MAX)), MemorySize::from_bits(u64::MAX.div_ceil(3)));
+
+    //everything compressed away
+    let ratio = CompressionRatio::new(MemorySize::from_bytes(100), MemorySize::new());
+    assert_eq!(ratio.ratio(), f64::INFINITY);
+    assert_eq!(ratio.space_saving(), 1.0);
+    assert_eq!(ratio.to_string(), "∞:1, saved 100%");
+    assert_eq!(ratio.apply(MemorySize::from_bytes(10)), MemorySize::new());
+}
+
+#[test]
+#[should_panic]
+fn compression_ratio_empty_original() {
+    let _ = crate::CompressionRatio::new(MemorySize::new(), MemorySize::new());
+}
+
+#[test]
+fn packet_segmentation() {
+    let mtu = MemorySize::from_bytes(1500);
+    let header = MemorySize::from_bytes(40);
+
+    let empty = MemorySize::new().segment(mtu, header);
+    assert_eq!(empty.packet_count, 0);
+    assert_eq!(empty.on_wire_size, MemorySize::new());
+
+    //exactly fills the packets
+    let full = MemorySize::from_bytes(2920).segment(mtu, header);
+    assert_eq!(full.packet_count, 2);
+    assert_eq!(full.last_packet_size, mtu);
+
+    let tiny = MemorySize::from_bytes(1).segment(mtu, header);
+    assert_eq!(tiny.packet_count, 1);
+    assert_eq!(tiny.last_packet_size, MemorySize::from_bytes(41));
+    assert_eq!(tiny.on_wire_size, MemorySize::from_bytes(41));
+}
+
+#[test]
+#[should_panic]
+fn packet_segmentation_header_too_large() {
+    let _ = MemorySize::from_bytes(10).segment(MemorySize::from_bytes(40), MemorySize::from_bytes(40));
+}
+
+#[test]
+fn multipart_plan() {
+    use crate::MultipartLimits;
+
+    let limits = MultipartLimits::S3;
+
+    let empty = limits.plan(MemorySize::new()).unwrap();
+    assert_eq!(empty.parts().collect::<Vec<_>>(), [MemorySize::new()]);
+
+    let small = limits.plan(MemorySize::from_bytes(100)).unwrap();
+    assert_eq!(small.part_count, 1);
+    assert_eq!(small.last_part_size, MemorySize::from_bytes(100));
+
+    let max = MemorySize::from_bytes((5 << 30) * 10_000);
+    let plan = limits.plan(max).unwrap();
+    assert_eq!(plan.part_size, limits.max_part_size);
+    assert_eq!(plan.part_count, 10_000);
+    assert_eq!(limits.plan(max + MemorySize::from_bytes(1)), None);
+
+    for total in [1u64, 5 << 20, (5 << 20) + 1, 123_456_789_012] {
+        let total = MemorySize::from_bytes(total);
+        let plan = limits.plan(total).unwrap();
+        assert!(plan.part_count <= limits.max_parts);
+        assert_eq!(plan.parts().sum::<MemorySize>(), total);
+    }
+
+    //rounding the part size up to a whole byte overflows
+    let unbounded = MultipartLimits { min_part_size: MemorySize::new(), max_part_size: MemorySize::from_bits(u64::MAX), max_parts: 1 };
+    assert_eq!(unbounded.plan(MemorySize::from_bits(u64::MAX)), None);
+    assert_eq!(unbounded.plan(MemorySize::from_bits(u64::MAX - 7)).unwrap().part_size, MemorySize::from_bits(u64::MAX - 7));
+    let huge_minimum = MultipartLimits { min_part_size: MemorySize::from_bits(u64::MAX), ..unbounded };
+    assert_eq!(huge_minimum.plan(MemorySize::from_bytes(1)), None);
+
+    let no_parts = MultipartLimits { max_parts: 0, ..limits };
+    assert_eq!(no_parts.plan(MemorySize::from_bytes(1)), None);
+}
+
+#[test]
+fn raid_capacity() {
+    use crate::RaidLevel;
+
+    let small = MemorySize::from_bytes(1000);
+    let large = MemorySize::from_bytes(1500);
+    let disks = [small, large, large, large];
+
+    let raid0 = RaidLevel::Raid0.capacity(&disks).unwrap();
+    assert_eq!(raid0.usable, MemorySize::from_bytes(4000));
+    assert_eq!(raid0.redundancy, MemorySize::new());
+    assert_eq!(raid0.unused, MemorySize::from_bytes(1500));
+
+    let raid1 = RaidLevel::Raid1.capacity(&disks).unwrap();
+    assert_eq!(raid1.usable, small);
+    assert_eq!(raid1.redundancy, MemorySize::from_bytes(3000));
+
+    let raid6 = RaidLevel::Raid6.capacity(&disks).unwrap();
+    assert_eq!(raid6.usable, MemorySize::from_bytes(2000));
+    assert_eq!(raid6.redundancy, MemorySize::from_bytes(2000));
+
+    let raid10 = RaidLevel::Raid10.capacity(&disks).unwrap();
+    assert_eq!(raid10.usable, MemorySize::from_bytes(2000));
+    assert_eq!(raid10.data_disks, 2);
+
+    //too few disks, or an odd number for RAID 10
+    assert_eq!(RaidLevel::Raid0.capacity(&[]), None);
+    assert_eq!(RaidLevel::Raid5.capacity(&disks[..2]), None);
+    assert_eq!(RaidLevel::Raid10.capacity(&[small; 5]), None);
+}
+
+#[test]
+fn memory_range() {
+    use crate::MemoryRange;
+
+    let range = MemoryRange::from_start_len(MemorySize::from_bits(3), MemorySize::from_bits(5));
+    assert_eq!(range, MemoryRange::new(MemorySize::from_bits(3), MemorySize::from_bits(8)));
+    assert!(!range.is_empty());
+    assert!(range.contains(MemorySize::from_bits(7)));
+    assert!(!range.contains(MemorySize::from_bits(2)));
+
+    let empty = MemoryRange::default();
+    assert!(empty.is_empty());
+    assert!(!empty.contains(MemorySize::new()));
+}
+
+#[test]
+fn http_headers() {
+    use crate::http::{self, ContentRange};
+    use crate::MemoryRange;
+
+    let bytes = |start, end| MemoryRange::new(MemorySize::from_bytes(start), MemorySize::from_bytes(end));
+    let total = MemorySize::from_bytes(10_000);
+
+    assert_eq!(http::parse_content_length(" 0 "), Ok(MemorySize::new()));
+    assert!(http::parse_content_length("").is_err());
+    assert!(http::parse_content_length("+5").is_err());
+    assert!(http::parse_content_length("99999999999999999999").is_err());
+
+    assert_eq!(http::parse_range("bytes=0-0", total), Ok(bytes(0, 1)));
+    assert_eq!(http::parse_range("bytes=0-499", total), Ok(bytes(0, 500)));
+    assert_eq!(http::parse_range("bytes=-20000", total), Ok(bytes(0, 10_000)));
+    assert!(http::parse_range("bytes=-0", total).is_err());
+    assert!(http::parse_range("bytes=5-4", total).is_err());
+    assert!(http::parse_range("items=0-4", total).is_err());
+    assert!(http::parse_range("bytes=0-4", MemorySize::new()).is_err());
+
+    let unknown_total: ContentRange = "bytes 10-19/*".parse().unwrap();
+    assert_eq!(unknown_total.range(), bytes(10, 20));
+    assert_eq!(unknown_total.total(), None);
+    assert_eq!(unknown_total.to_string(), "bytes 10-19/*");
+    assert!("bytes 10-19/15".parse::<ContentRange>().is_err());
+    assert!("bytes */1000".parse::<ContentRange>().is_err());
+}
+
+#[test]
+fn http_header_formatting() {
+    use crate::http::{self, ContentRange};
+    use crate::MemoryRange;
+
+    let bytes = |start, end| MemoryRange::new(MemorySize::from_bytes(start), MemorySize::from_bytes(end));
+    let total = MemorySize::from_bytes(10_000);
+
+    assert_eq!(http::format_content_length(MemorySize::new()), Ok("0".to_string()));
+    assert_eq!(http::format_content_length(total).and_then(|value| http::parse_content_length(&value)), Ok(total));
+
+    assert_eq!(http::format_range(bytes(0, 1)), Ok("bytes=0-0".to_string()));
+    assert_eq!(http::format_range(bytes(500, 10_000)).and_then(|value| http::parse_range(&value, total)), Ok(bytes(500, 10_000)));
+    assert!(http::format_range(bytes(5, 5)).is_err());
+    assert!(http::format_range(MemoryRange::new(MemorySize::from_bits(4), MemorySize::from_bytes(2))).is_err());
+
+    let header = ContentRange::new(bytes(0, 500), Some(total)).unwrap();
+    assert_eq!(header.to_string().parse(), Ok(header));
+    assert_eq!(ContentRange::new(bytes(0, 0), None), Err(http::InvalidHeaderError));
+    assert_eq!(ContentRange::new(bytes(0, 1), Some(MemorySize::from_bits(12))), Err(http::InvalidHeaderError));
+    assert_eq!(ContentRange::new(MemoryRange::new(MemorySize::new(), MemorySize::from_bits(4)), None), Err(http::InvalidHeaderError));
+}
+
+#[test]
+fn io_take_sized() {
+    use crate::io::ReadSizedExt;
+    use std::io::Read;
+
+    let data = [1u8; 32];
+    let mut out = Vec::new();
+
+    let mut reader = data.as_slice().take_sized(MemorySize::from_bits(20));
+    reader.read_to_end(&mut out).unwrap();
+    assert_eq!(out.len(), 2);//partial bytes are not read
+
+    out.clear();
+    let mut reader = data.as_slice().take_sized(MemorySize::from_bytes(1000));
+    reader.read_to_end(&mut out).unwrap();
+    assert_eq!(out.len(), 32);
+}
+
+#[test]
+fn io_read_exact_sized() {
+    use crate::io::ReadSizedExt;
+    use std::io::ErrorKind;
+
+    let cap = MemorySize::from_bytes(4);
+    let mut data: &[u8] = &[9, 8, 7, 6, 5, 4];
+
+    assert!(data.read_exact_sized(MemorySize::new(), cap).unwrap().is_empty());
+    assert_eq!(data.read_exact_sized(cap, cap).unwrap(), [9, 8, 7, 6]);
+
+    let err = |result: std::io::Result<Vec<u8>>| result.unwrap_err().kind();
+    assert_eq!(err(data.read_exact_sized(MemorySize::from_bits(4), cap)), ErrorKind::InvalidInput);
+    assert_eq!(err(data.read_exact_sized(MemorySize::from_bytes(5), cap)), ErrorKind::InvalidData);
+    assert_eq!(err(data.read_exact_sized(MemorySize::from_bytes(3), cap)), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn byte_rate() {
+    use crate::ByteRate;
+    use std::time::Duration;
+
+    let rate = ByteRate::from_size_and_duration(MemorySize::from_bits(10), Duration::from_secs(3));
+    assert_eq!(rate.size_per_second(), MemorySize::from_bits(3));
+    assert_eq!(rate.time_for(MemorySize::from_bits(9)), Some(Duration::from_secs(3)));
+    assert_eq!(rate.time_for(MemorySize::new()), Some(Duration::ZERO));
+
+    //a zero rate never finishes, and u64::MAX seconds rounds past the largest Duration
+    assert_eq!(ByteRate::per_second(MemorySize::new()).time_for(MemorySize::from_bits(1)), None);
+    assert_eq!(ByteRate::per_second(MemorySize::new()).time_for(MemorySize::new()), None);
+    assert_eq!(ByteRate::per_second(MemorySize::from_bits(1)).time_for(MemorySize::from_bits(u64::MAX)), None);
+    assert!(ByteRate::per_second(MemorySize::from_bits(2)).time_for(MemorySize::from_bits(u64::MAX)).is_some());
+    assert!(ByteRate::per_second(MemorySize::from_bytes(1)) < ByteRate::per_second(MemorySize::from_bytes(2)));
+}
+
+#[test]
+fn io_throttle() {
+    use crate::io::Throttle;
+    use crate::ByteRate;
+    use std::io::{Read, Write};
+    use std::time::{Duration, Instant};
+
+    let rate = ByteRate::per_second(MemorySize::from_bytes(100_000));
+
+    //the first second of data is allowed through immediately, then the rest is limited
+    let start = Instant::now();
+    let mut writer = Throttle::new(Vec::new(), rate);
+    writer.write_all(&[0; 120_000]).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(150));
+    assert_eq!(writer.get_ref().len(), 120_000);
+
+    let data = vec![7u8; 1000];
+    let mut reader = Throttle::new(data.as_slice(), rate);
+    let mut out = Vec::new();
+    reader.read_to_end(&mut out).unwrap();
+    assert_eq!(out, data);
+}
+
+#[test]
+fn io_counting_and_progress() {
+    use crate::io::{copy_with_progress, Counting};
+    use std::io::Read;
+
+    let data: Vec<u8> = (0..=255).collect();
+    let mut reader = Counting::new(data.as_slice());
+    let mut first = [0u8; 10];
+    reader.read_exact(&mut first).unwrap();
+    assert_eq!(reader.transferred(), MemorySize::from_bytes(10));
+
+    let mut output = Counting::new(Vec::new());
+    let mut reports = Vec::new();
+    let copied = copy_with_progress(&mut reader, &mut output, MemorySize::from_bytes(100), |copied, _| reports.push(copied)).unwrap();
+
+    assert_eq!(copied, MemorySize::from_bytes(246));
+    assert_eq!(reports, [100, 200, 246].map(MemorySize::from_bytes));
+    assert_eq!(output.transferred(), copied);
+    assert_eq!(output.into_inner(), data[10..]);
+
+    //chunks larger than the copy buffer are reported once they have been filled by several reads
+    let data = vec![7u8; 200_000];
+    let mut reports = Vec::new();
+    let copied = copy_with_progress(&mut data.as_slice(), &mut Vec::new(), MemorySize::from_bytes(150_000), |copied, _| reports.push(copied)).unwrap();
+    assert_eq!(copied, MemorySize::from_bytes(200_000));
+    assert_eq!(reports, [150_000, 200_000].map(MemorySize::from_bytes));
+
+    //a chunk far too large to allocate is only reported at the end
+    let mut reports = Vec::new();
+    let mut output = Vec::new();
+    copy_with_progress(&mut data.as_slice(), &mut output, MemorySize::from_bytes(1 << 40), |copied, _| reports.push(copied)).unwrap();
+    assert_eq!(reports, [MemorySize::from_bytes(200_000)]);
+    assert_eq!(output, data);
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn async_io_adapters() {
+    use crate::async_io::{AsyncCounting, AsyncReadSizedExt, AsyncThrottle};
+    use crate::ByteRate;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let data: Vec<u8> = (0..=255).collect();
+
+    let mut reader = AsyncCounting::new(data.as_slice().take_sized(MemorySize::from_bytes(200)));
+    let mut out = Vec::new();
+    reader.read_to_end(&mut out).await.unwrap();
+    assert_eq!(reader.transferred(), MemorySize::from_bytes(200));
+    assert_eq!(out, data[..200]);
+
+    let rate = ByteRate::per_second(MemorySize::from_bytes(100_000));
+    let start = std::time::Instant::now();
+    let mut writer = AsyncThrottle::new(AsyncCounting::new(Vec::new()), rate);
+    writer.write_all(&[0; 120_000]).await.unwrap();
+    assert!(start.elapsed() >= std::time::Duration::from_millis(150));
+    assert_eq!(writer.get_ref().transferred(), MemorySize::from_bytes(120_000));
+
+    let mut reader = AsyncThrottle::new(data.as_slice(), rate);
+    let mut out = Vec::new();
+    reader.read_to_end(&mut out).await.unwrap();
+    assert_eq!(out, data);
+}
+
+#[cfg(feature = "criterion")]
+#[test]
+fn criterion_throughput() {
+    use criterion::Throughput;
+
+    assert!(matches!(Throughput::from(MemorySize::new()), Throughput::Bytes(0)));
+    assert!(matches!(Throughput::from(MemorySize::from_bytes(3)), Throughput::Bytes(3)));
+    assert!(matches!(Throughput::from(MemorySize::from_bits(u64::MAX)), Throughput::Bits(u64::MAX)));
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn bytemuck_casts() {
+    let sizes = [MemorySize::from_bits(1), MemorySize::from_bytes(2), MemorySize::new()];
+    let raw: &[u64] = bytemuck::cast_slice(&sizes);
+    assert_eq!(raw, [1, 16, 0]);
+
+    let bytes: &[u8] = bytemuck::cast_slice(&sizes);
+    assert_eq!(bytes.len(), 24);
+    let back: &[MemorySize] = bytemuck::cast_slice(bytes);
+    assert_eq!(back, sizes);
+
+    let zeroed: MemorySize = bytemuck::Zeroable::zeroed();
+    assert_eq!(zeroed, MemorySize::new());
+}
+
+#[test]
+fn non_max_size() {
+    use crate::MemorySizeNonMax;
+
+    assert_eq!(std::mem::size_of::<Option<MemorySizeNonMax>>(), 8);
+
+    assert_eq!(MemorySizeNonMax::default().get(), MemorySize::new());
+    assert_eq!(MemorySizeNonMax::MAX.get(), MemorySize::from_bits(u64::MAX - 1));
+
+    let small = MemorySizeNonMax::new(MemorySize::from_bytes(1)).unwrap();
+    let large = MemorySizeNonMax::new(MemorySize::from_bytes(2)).unwrap();
+    assert!(small < large);
+    assert!(large < MemorySizeNonMax::MAX);
+    assert_eq!(MemorySize::from(large), MemorySize::from_bytes(2));
+    assert_eq!(format!("{:?}", small), "MemorySizeNonMax(MemorySize { size_bits: 8 })");
+}
+
+#[test]
+fn memory_limit() {
+    use crate::MemoryLimit;
+
+    let size = MemorySize::from_bytes(100);
+    let limit = MemoryLimit::from(size);
+
+    assert_eq!(limit, size);
+    assert_eq!(size, limit);
+    assert!(MemoryLimit::Unlimited > size);
+    assert!(MemoryLimit::Unlimited > limit);
+    assert!(size + MemorySize::from_bits(1) > limit);
+    assert_eq!(MemoryLimit::default(), MemoryLimit::Unlimited);
+    assert_eq!(MemoryLimit::Unlimited.as_size(), None);
+    assert!(MemoryLimit::Unlimited.allows(MemorySize::from_bits(u64::MAX)));
+
+    for keyword in ["unlimited", "MAX", " None "] {
+        assert_eq!(keyword.parse(), Ok(MemoryLimit::Unlimited));
+    }
+    assert_eq!("100".parse(), Ok(limit));
+    assert!("".parse::<MemoryLimit>().is_err());
+    assert!("infinite".parse::<MemoryLimit>().is_err());
+    assert!(u64::MAX.to_string().parse::<MemoryLimit>().is_err());
+
+    assert_eq!(MemoryLimit::Unlimited.to_string(), "unlimited");
+}
+
+#[test]
+fn memory_limit_round_trip() {
+    use crate::MemoryLimit;
+
+    let limits = [
+        MemoryLimit::Unlimited,
+        MemoryLimit::Limited(MemorySize::from_bytes(3 << 29)),
+        MemoryLimit::Limited(MemorySize::from_bytes(1_500_000_001)),
+        MemoryLimit::Limited(MemorySize::from_bits(12)),
+        MemoryLimit::Limited(MemorySize::new()),
+    ];
+    for limit in limits {
+        assert_eq!(limit.to_string().parse(), Ok(limit), "parsing {}", limit);
+    }
+    assert_eq!(MemoryLimit::Limited(MemorySize::from_bytes(3 << 29)).to_string(), "1536 MiB");
+    assert_eq!("1.5 GiB".parse(), Ok(MemoryLimit::Limited(MemorySize::from_bytes(3 << 29))));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_sizes_and_limits() {
+    use crate::MemoryLimit;
+
+    let size = MemorySize::from_bytes(4096);
+    assert_eq!(serde_json::to_string(&size).unwrap(), "4096");
+    assert_eq!(serde_json::from_str::<MemorySize>("4096").unwrap(), size);
+    assert!(serde_json::to_string(&MemorySize::from_bits(3)).is_err());
+    assert!(serde_json::from_str::<MemorySize>(&u64::MAX.to_string()).is_err());
+
+    assert_eq!(serde_json::to_string(&MemoryLimit::Unlimited).unwrap(), "\"unlimited\"");
+    assert_eq!(serde_json::to_string(&MemoryLimit::Limited(size)).unwrap(), "4096");
+    assert_eq!(serde_json::from_str::<MemoryLimit>("\"max\"").unwrap(), MemoryLimit::Unlimited);
+    assert_eq!(serde_json::from_str::<MemoryLimit>("4096").unwrap(), size);
+    assert_eq!(serde_json::from_str::<MemoryLimit>("\"4096\"").unwrap(), size);
+    assert!(serde_json::from_str::<MemoryLimit>("\"lots\"").is_err());
+}
+
+#[test]
+fn bounded_size() {
+    use crate::{BoundedSize, MemorySizeError};
+
+    type Buffer = BoundedSize<16, 64>;
+
+    assert!(Buffer::new(MemorySize::from_bytes(16)).is_ok());
+    assert!(Buffer::new(MemorySize::from_bytes(64)).is_ok());
+    assert_eq!(MemorySize::from(Buffer::try_from(MemorySize::from_bytes(20)).unwrap()), MemorySize::from_bytes(20));
+
+    let err = Buffer::new(MemorySize::from_bits(64 * 8 + 1)).unwrap_err();
+    assert_eq!(err, MemorySizeError::OutOfBounds {
+        size: MemorySize::from_bits(64 * 8 + 1),
+        min: Buffer::MIN,
+        max: Buffer::MAX,
+    });
+    assert!(err.to_string().contains("above the maximum"));
+    assert!(Buffer::new(MemorySize::new()).unwrap_err().to_string().contains("below the minimum"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_bounded_size() {
+    use crate::BoundedSize;
+
+    #[derive(serde::Deserialize)]
+    struct Config {
+        cache: BoundedSize<1024, 4096>,
+    }
+
+    let config: Config = serde_json::from_str(r#"{"cache": 2048}"#).unwrap();
+    assert_eq!(config.cache.get(), MemorySize::from_bytes(2048));
+    assert_eq!(serde_json::to_string(&config.cache).unwrap(), "2048");
+
+    let err = serde_json::from_str::<Config>(r#"{"cache": 10}"#).err().unwrap();
+    assert!(err.to_string().contains("below the minimum"));
+}
+
+#[test]
+fn memory_limit_parse_errors() {
+    use crate::MemoryLimit;
+
+    let err = "".parse::<MemoryLimit>().unwrap_err();
+    assert_eq!(err.span(), 0..0);
+    assert_eq!(err.suggestion(), None);
+
+    let err = " NOEN ".parse::<MemoryLimit>().unwrap_err();
+    assert_eq!(err.span(), 1..5);
+    assert_eq!(err.suggestion(), Some("none"));
+
+    assert_eq!("mx".parse::<MemoryLimit>().unwrap_err().suggestion(), Some("max"));
+    assert_eq!("12x".parse::<MemoryLimit>().unwrap_err().suggestion(), None);
+    assert_eq!("infinite".parse::<MemoryLimit>().unwrap_err().suggestion(), None);
+
+    let err = "1024 parsecs".parse::<MemoryLimit>().unwrap_err();
+    assert_eq!(err.to_string(), "invalid memory limit at bytes 0..12, expected a size or one of \"unlimited\", \"max\" or \"none\"");
+}
+
+#[test]
+fn display_round_trip() {
+    let cases = [
+        (MemorySize::new(), "0 B"),
+        (MemorySize::from_bytes(1), "1 B"),
+        (MemorySize::from_bytes(1536), "1536 B"),
+        (MemorySize::from_bytes(3 << 20), "3 MiB"),
+        (MemorySize::from_bytes(1 << 60), "1 EiB"),
+        (MemorySize::from_bytes(2047 << 50), "2047 PiB"),
+        (MemorySize::from_bits(u64::MAX), "18446744073709551615 bit"),
+    ];
+    for (size, expected) in cases {
+        assert_eq!(size.display_round_trip().to_string(), expected);
+    }
+}
+
+#[test]
+fn display_templates() {
+    let kib = MemorySize::from_bytes(1024);
+    let display = kib.display_round_trip();
+
+    assert_eq!(display.with_template("{value} {unit_long}").to_string(), "1 kibibyte");
+    assert_eq!(display.with_template("[{unit}] {value}").to_string(), "[KiB] 1");
+    assert_eq!(display.with_template("{value}{value}").to_string(), "11");
+    //unknown placeholders and stray braces are kept
+    assert_eq!(display.with_template("{size} {unit} {").to_string(), "{size} KiB {");
+    assert_eq!(display.with_template("").to_string(), "");
+
+    let bits = MemorySize::from_bits(3).display_round_trip();
+    assert_eq!(bits.with_template("{value} {unit_long}").to_string(), "3 bits");
+    assert_eq!(MemorySize::new().display_round_trip().with_template("{value} {unit_long}").to_string(), "0 bytes");
+}
+
+#[cfg(feature = "num-rational")]
+#[test]
+fn mul_ratio_rounding() {
+    use crate::RoundingMode;
+    use num_rational::Ratio;
+
+    let size = MemorySize::from_bits(10);
+    //10 * 1/4 = 2.5
+    assert_eq!(size.mul_ratio(Ratio::new(1, 4), RoundingMode::Floor), MemorySize::from_bits(2));
+    assert_eq!(size.mul_ratio(Ratio::new(1, 4), RoundingMode::Ceil), MemorySize::from_bits(3));
+    assert_eq!(size.mul_ratio(Ratio::new(1, 4), RoundingMode::Nearest), MemorySize::from_bits(3));
+    //repeatedly scaling by 2/3 then 3/2 is exact
+    let big = MemorySize::from_bits(u64::MAX / 2);
+    assert_eq!(big.mul_ratio(Ratio::new(3, 2), RoundingMode::Floor).mul_ratio(Ratio::new(2, 3), RoundingMode::Floor), MemorySize::from_bits(u64::MAX / 2 - 1));
+    assert_eq!(MemorySize::new().mul_ratio(Ratio::new(7, 3), RoundingMode::Ceil), MemorySize::new());
+}
+
+#[cfg(feature = "num-rational")]
+#[test]
+#[should_panic]
+fn mul_ratio_overflow() {
+    MemorySize::from_bits(u64::MAX).mul_ratio(num_rational::Ratio::new(2, 1), crate::RoundingMode::Floor);
+}
+
+#[test]
+fn display_human_rounding() {
+    let human = |bytes: u64| MemorySize::from_bytes(bytes).display_human().to_string();
+
+    assert_eq!(human(0), "0 B");
+    assert_eq!(human(1023), "1023 B");
+    assert_eq!(human(1024), "1.00 KiB");
+    assert_eq!(human(1029), "1.00 KiB");
+    assert_eq!(human(1030), "1.01 KiB");
+    //just under 1 MiB rounds up into the next unit
+    assert_eq!(human((1 << 20) - 1), "1.00 MiB");
+    assert_eq!(human(u64::MAX / 8), "2.00 EiB");
+    assert_eq!(MemorySize::from_bits(u64::MAX).display_human().to_string(), "2.00 EiB");
+    assert_eq!(MemorySize::from_bits(13).display_human().to_string(), "13 bit");
+    assert_eq!(MemorySize::from_bytes(3 << 40).display_human().with_template("{value} {unit_long}").to_string(), "3.00 tebibytes");
+}
+
+#[test]
+fn fallible_never_panics() {
+    use crate::{fallible, RoundingMode};
+
+    let max = MemorySize::from_bits(u64::MAX);
+    assert_eq!(fallible::sum(&[max, MemorySize::from_bits(1)]), None);
+    assert_eq!(fallible::sum(&[]), Some(MemorySize::new()));
+    assert_eq!(fallible::align_up(max, MemorySize::new()), Some(max));
+    assert_eq!(fallible::align_up(max, max), Some(max));
+    assert_eq!(fallible::align_up(MemorySize::new(), max), Some(MemorySize::new()));
+    assert_eq!(fallible::round_up_byte(max), None);
+    assert_eq!(fallible::align_up_all(max, &[]), Some(max));
+    assert_eq!(fallible::align_up_all(max, &[MemorySize::new(), max]), Some(max));
+    assert_eq!(fallible::align_up_all(MemorySize::from_bits(1), &[MemorySize::from_bits(1 << 63), MemorySize::from_bits(3)]), None);
+    assert_eq!(fallible::mul(max, 1), Some(max));
+    assert_eq!(fallible::mul(max, 0), Some(MemorySize::new()));
+    assert_eq!(fallible::div(max, 1), Some(max));
+    assert_eq!(fallible::div(MemorySize::new(), 0), None);
+    assert_eq!(fallible::div_size(max, MemorySize::from_bits(1)), Some(u64::MAX));
+    assert_eq!(fallible::div_size(MemorySize::new(), MemorySize::new()), None);
+    assert_eq!(fallible::rem(max, max), Some(MemorySize::new()));
+    assert_eq!(fallible::rem(max, MemorySize::new()), None);
+    assert_eq!(fallible::scale(max, u64::MAX, u64::MAX, RoundingMode::Ceil), Some(max));
+    assert_eq!(fallible::scale(max, 1, 0, RoundingMode::Floor), None);
+    assert_eq!(fallible::shl(MemorySize::new(), u32::MAX), Some(MemorySize::new()));
+    assert_eq!(fallible::shl(max, 1), None);
+    assert_eq!(fallible::shr(max, 63), Some(MemorySize::from_bits(1)));
+    assert_eq!(fallible::shr(max, u32::MAX), None);
+    assert_eq!(fallible::next_power_of_two(MemorySize::new()), Some(MemorySize::from_bits(1)));
+    assert_eq!(fallible::next_power_of_two(max), None);
+    assert_eq!(fallible::prev_power_of_two(max), Some(MemorySize::from_bits(1 << 63)));
+    assert_eq!(fallible::prev_power_of_two(MemorySize::new()), None);
+    assert_eq!(fallible::ilog2(max), Some(63));
+    assert_eq!(fallible::ilog2(MemorySize::new()), None);
+    assert_eq!(fallible::ilog2_bytes(max), Some(60));
+    assert_eq!(fallible::ilog2_bytes(MemorySize::from_bits(7)), None);
+    assert_eq!(fallible::div_ceil(max, MemorySize::from_bits(2)), Some(1 << 63));
+    assert_eq!(fallible::div_ceil(MemorySize::new(), MemorySize::new()), None);
+    assert_eq!(fallible::from_kib(u64::MAX / 8192), Some(MemorySize::from_kib(u64::MAX / 8192)));
+    assert_eq!(fallible::from_mib(u64::MAX / (8 << 20) + 1), None);
+    assert_eq!(fallible::from_gib(1), Some(MemorySize::from_gib(1)));
+    assert_eq!(fallible::from_gib(u64::MAX), None);
+    assert_eq!(fallible::from_kb(3), Some(MemorySize::from_kb(3)));
+    assert_eq!(fallible::from_mb(u64::MAX / 8_000_000 + 1), None);
+    assert_eq!(fallible::from_gb(u64::MAX / 8_000_000_000), Some(MemorySize::from_gb(u64::MAX / 8_000_000_000)));
+    assert_eq!(fallible::align_nearest(max, MemorySize::new()), Some(max));
+    assert_eq!(fallible::align_nearest(max, MemorySize::from_bits(1 << 63)), None);
+    assert_eq!(fallible::align_nearest(MemorySize::from_bits((1 << 62) - 1), MemorySize::from_bits(1 << 63)), Some(MemorySize::new()));
+    assert_eq!(fallible::split_at(max, max), Some((max, MemorySize::new())));
+    assert_eq!(fallible::split_at(MemorySize::new(), MemorySize::from_bits(1)), None);
+    assert_eq!(fallible::chunks(max, max).map(Iterator::count), Some(1));
+    assert!(fallible::chunks(MemorySize::new(), MemorySize::new()).is_none());
+    assert_eq!(fallible::from_bytes(u64::MAX / 8), Some(MemorySize::from_bits(u64::MAX - 7)));
+}
+
+#[test]
+fn arg_parser_constraints() {
+    use crate::{MemorySizeError, MemorySizeParseError};
+
+    let parser = MemorySize::arg_parser().max(MemorySize::from_bytes(100));
+    assert_eq!(parser.parse(" 100 "), Ok(MemorySize::from_bytes(100)));
+    assert_eq!(parser.parse("101"), Err(MemorySizeError::OutOfBounds {
+        size: MemorySize::from_bytes(101),
+        min: MemorySize::new(),
+        max: MemorySize::from_bytes(100),
+    }));
+    assert_eq!(parser.parse("ten"), Err(MemorySizeError::Parse(MemorySizeParseError::InvalidNumber { offset: 0 })));
+    assert_eq!(parser.parse(&u64::MAX.to_string()), Err(MemorySizeError::Parse(MemorySizeParseError::Overflow { offset: 0 })));
+
+    let bits = MemorySize::arg_parser().alignment(MemorySize::from_bits(3)).whole_bytes();
+    assert_eq!(bits.check(MemorySize::from_bytes(3)), Ok(MemorySize::from_bytes(3)));
+    assert_eq!(bits.check(MemorySize::from_bits(3)), Err(MemorySizeError::Unaligned {
+        size: MemorySize::from_bits(3),
+        alignment: MemorySize::from_bits(24),
+    }));
+    let nibbles = MemorySize::arg_parser().whole_bytes().alignment(MemorySize::from_bits(4));
+    assert_eq!(nibbles, MemorySize::arg_parser().alignment(MemorySize::from_bits(4)).whole_bytes());
+    assert_eq!(nibbles.check(MemorySize::from_bytes(1)), Ok(MemorySize::from_bytes(1)));
+    assert_eq!(nibbles.check(MemorySize::from_bits(4)), Err(MemorySizeError::Unaligned {
+        size: MemorySize::from_bits(4),
+        alignment: MemorySize::from_bytes(1),
+    }));
+    assert_eq!(
+        MemorySizeError::Unaligned { size: MemorySize::from_bits(3), alignment: MemorySize::from_bits(24) }.to_string(),
+        "size 3 bit is not a multiple of 3 B"
+    );
+}
+
+#[test]
+fn sum_slice_overflow() {
+    use crate::MemorySizeError;
+
+    let max = MemorySize::from_bits(u64::MAX);
+    assert_eq!(MemorySize::sum_slice(&[]), Ok(MemorySize::new()));
+    assert_eq!(MemorySize::sum_slice(&[max]), Ok(max));
+    assert_eq!(MemorySize::sum_slice(&[max, MemorySize::from_bits(1)]), Err(MemorySizeError::Overflow));
+
+    let many = [MemorySize::from_bytes(1); 10_000];
+    assert_eq!(MemorySize::sum_slice(&many), Ok(MemorySize::from_bytes(10_000)));
+}
+
+#[test]
+fn pad_to_presets() {
+    use crate::AlignmentPreset;
+
+    assert_eq!(MemorySize::new().pad_to_cache_line(), (MemorySize::new(), MemorySize::new()));
+    assert_eq!(MemorySize::CACHE_LINE.pad_to_cache_line(), (MemorySize::CACHE_LINE, MemorySize::new()));
+    assert_eq!(
+        MemorySize::from_bits(1).pad_to(AlignmentPreset::HugePage),
+        (MemorySize::from_bytes(2 << 20), MemorySize::from_bits((2 << 23) - 1))
+    );
+    assert_eq!(MemorySize::from_bytes(300).pad_to(AlignmentPreset::GpuBufferOffset).1, MemorySize::from_bytes(212));
+}
+
+#[cfg(all(target_arch = "x86_64", unix))]
+#[test]
+fn target_layout_matches_host() {
+    use crate::{Primitive, SizeAlign, TargetLayout};
+
+    let host = TargetLayout::from_triple("x86_64-unknown-linux-gnu").unwrap();
+    assert_eq!(host.primitive(Primitive::I8), SizeAlign::of::<u8>());
+    assert_eq!(host.primitive(Primitive::I64), SizeAlign::of::<u64>());
+    assert_eq!(host.primitive(Primitive::I128), SizeAlign::of::<u128>());
+    assert_eq!(host.primitive(Primitive::F64), SizeAlign::of::<f64>());
+    assert_eq!(host.primitive(Primitive::Pointer), SizeAlign::of::<usize>());
+}
+
+#[test]
+fn guard_pages() {
+    let page = MemorySize::from_bytes(4096);
+
+    let (total, usable) = MemorySize::new().with_guard_pages(page, 2);
+    assert_eq!(total, MemorySize::from_bytes(4 * 4096));
+    assert!(usable.is_empty());
+    assert_eq!(usable.start(), MemorySize::from_bytes(2 * 4096));
+
+    let (total, usable) = page.with_guard_pages(page, 0);
+    assert_eq!(total, page);
+    assert_eq!(usable.end(), page);
+}
+
+#[test]
+#[should_panic]
+fn guard_pages_overflow() {
+    MemorySize::from_bytes(4096).with_guard_pages(MemorySize::from_bytes(4096), u64::MAX / 4096);
+}
+
+#[test]
+fn parse_human_sizes() {
+    use crate::MemorySizeParseError;
+
+    let cases = [
+        ("0", MemorySize::new()),
+        ("42", MemorySize::from_bytes(42)),
+        ("  7B ", MemorySize::from_bytes(7)),
+        ("1 byte", MemorySize::from_bytes(1)),
+        ("2 KiB", MemorySize::from_bytes(2048)),
+        ("2 kiB", MemorySize::from_bytes(2048)),
+        ("2 KB", MemorySize::from_bytes(2000)),
+        ("1.5 MB", MemorySize::from_bytes(1_500_000)),
+        (".5 KiB", MemorySize::from_bytes(512)),
+        ("3 Gibibytes", MemorySize::from_bytes(3 << 30)),
+        ("1 terabyte", MemorySize::from_bytes(1_000_000_000_000)),
+        ("1.5 B", MemorySize::from_bits(12)),
+        //not a whole number of bits, so rounded to the nearest byte
+        ("0.1 KiB", MemorySize::from_bytes(102)),
+        ("1 EiB", MemorySize::from_bytes(1 << 60)),
+    ];
+    for (text, size) in cases {
+        assert_eq!(text.parse(), Ok(size), "parsing {:?}", text);
+    }
+
+    for text in [".", "KiB", "1.2.3", "-1 B", "1.00000000000000000001 B"] {
+        assert_eq!(text.parse::<MemorySize>(), Err(MemorySizeParseError::InvalidNumber { offset: 0 }), "parsing {:?}", text);
+    }
+    assert_eq!("1 KiBs".parse::<MemorySize>(), Err(MemorySizeParseError::UnknownUnit { offset: 2, suggestion: Some("KiB") }));
+    assert_eq!("1 XB".parse::<MemorySize>(), Err(MemorySizeParseError::UnknownUnit { offset: 2, suggestion: None }));
+    assert_eq!("2 EiB".parse::<MemorySize>(), Err(MemorySizeParseError::Overflow { offset: 0 }));
+    assert_eq!("99999999999999999999999999999999999999999".parse::<MemorySize>(), Err(MemorySizeParseError::Overflow { offset: 0 }));
+}
+
+#[test]
+fn parse_display_round_trip() {
+    for bytes in [0, 1, 1000, 1024, 4097, 3 << 40, u64::MAX / 8] {
+        let size = MemorySize::from_bytes(bytes);
+        assert_eq!(size.display_round_trip().to_string().parse(), Ok(size));
+    }
+}
+
+#[test]
+fn parse_bit_units() {
+    let cases = [
+        ("512 bit", MemorySize::from_bits(512)),
+        ("3 bits", MemorySize::from_bits(3)),
+        ("12bit", MemorySize::from_bits(12)),
+        ("1 b", MemorySize::from_bits(1)),
+        ("8 Kib", MemorySize::from_bits(8192)),
+        ("8 kibit", MemorySize::from_bits(8192)),
+        ("1 kb", MemorySize::from_bits(1000)),
+        ("100 Mbit", MemorySize::from_bits(100_000_000)),
+        ("1.5 Gbits", MemorySize::from_bits(1_500_000_000)),
+        ("2 megabits", MemorySize::from_bits(2_000_000)),
+        ("1 Mebibit", MemorySize::from_bits(1 << 20)),
+    ];
+    for (text, size) in cases {
+        assert_eq!(text.parse(), Ok(size), "parsing {:?}", text);
+    }
+    assert_eq!("1.5 bit".parse(), Ok(MemorySize::from_bits(2)));
+    assert_eq!("0.001 kib".parse(), Ok(MemorySize::from_bits(1)));
+    assert!("1 bitt".parse::<MemorySize>().is_err());
+    assert!("1 Kibyte".parse::<MemorySize>().is_err());
+
+    //round trip output in bits parses back
+    let size = MemorySize::from_bits(13);
+    assert_eq!(size.display_round_trip().to_string().parse(), Ok(size));
+}
+
+#[test]
+fn parse_digit_separators() {
+    use crate::{MemorySizeParseError, ParseOptions};
+
+    let english = ParseOptions::new().digit_separators('.', Some(','));
+    let european = ParseOptions::new().digit_separators(',', Some('.'));
+    let spaced = ParseOptions::new().digit_separators(',', Some(' '));
+
+    assert_eq!(MemorySize::parse_with("1,048,576 B", &english), Ok(MemorySize::from_bytes(1 << 20)));
+    assert_eq!(MemorySize::parse_with("1,024.5 KiB", &english), Ok(MemorySize::from_bytes(1_049_088)));
+    assert_eq!(MemorySize::parse_with("1.024 kB", &european), Ok(MemorySize::from_bytes(1_024_000)));
+    assert_eq!(MemorySize::parse_with("2,5 MB", &european), Ok(MemorySize::from_bytes(2_500_000)));
+    assert_eq!(MemorySize::parse_with("1 048 576 B", &spaced), Ok(MemorySize::from_bytes(1 << 20)));
+    assert_eq!(MemorySize::parse_with("1 KiB", &spaced), Ok(MemorySize::from_bytes(1024)));
+
+    for text in ["10,48 B", "1,0485 B", ",123 B", "1.5,000 B"] {
+        assert_eq!(MemorySize::parse_with(text, &english), Err(MemorySizeParseError::InvalidNumber { offset: 0 }), "parsing {:?}", text);
+    }
+    //the default options have no thousands separator
+    assert!("1,024 B".parse::<MemorySize>().is_err());
+}
+
+#[test]
+#[should_panic]
+fn parse_options_same_separators() {
+    crate::ParseOptions::new().digit_separators(',', Some(','));
+}
+
+#[test]
+fn parse_error_offsets() {
+    use crate::{MemorySizeError, MemorySizeParseError};
+
+    assert_eq!("".parse::<MemorySize>(), Err(MemorySizeParseError::Empty));
+    assert_eq!("  \t".parse::<MemorySize>(), Err(MemorySizeParseError::Empty));
+
+    let err = "  12  parsecs ".parse::<MemorySize>().unwrap_err();
+    assert_eq!(err, MemorySizeParseError::UnknownUnit { offset: 6, suggestion: None });
+    assert_eq!(err.offset(), 6);
+    assert_eq!(err.to_string(), "unknown unit at byte 6");
+
+    assert_eq!("   x".parse::<MemorySize>().unwrap_err().offset(), 3);
+    assert_eq!(" 3 EiB".parse::<MemorySize>(), Err(MemorySizeParseError::Overflow { offset: 1 }));
+    assert_eq!(
+        MemorySizeError::from(MemorySizeParseError::Empty).to_string(),
+        "failed to parse size: empty size, expected a number followed by a unit"
+    );
+}
+
+#[test]
+fn parse_composite_sizes() {
+    use crate::MemorySizeParseError;
+
+    let cases = [
+        ("1 GiB 512 MiB", MemorySize::from_bytes(3 << 29)),
+        ("2GB+256MB", MemorySize::from_bytes(2_256_000_000)),
+        ("1 KiB + 1 bit", MemorySize::from_bits(8193)),
+        ("1 KiB + 24", MemorySize::from_bytes(1048)),
+        (" 1MiB1KiB ", MemorySize::from_bytes(1025 << 10)),
+    ];
+    for (text, size) in cases {
+        assert_eq!(text.parse(), Ok(size), "parsing {:?}", text);
+    }
+
+    assert_eq!("1 GiB +".parse::<MemorySize>(), Err(MemorySizeParseError::InvalidNumber { offset: 7 }));
+    assert_eq!("1 GiB ++ 1 MiB".parse::<MemorySize>(), Err(MemorySizeParseError::InvalidNumber { offset: 7 }));
+    assert_eq!("24 1 KiB".parse::<MemorySize>(), Err(MemorySizeParseError::UnknownUnit { offset: 3, suggestion: None }));
+    assert_eq!("1 EiB 1 EiB".parse::<MemorySize>(), Err(MemorySizeParseError::Overflow { offset: 6 }));
+}
+
+#[test]
+fn parse_options_strictness() {
+    use crate::{MemorySizeParseError, ParseOptions};
+
+    let parse = |text, options: &ParseOptions| MemorySize::parse_with(text, options);
+
+    let case_sensitive = ParseOptions::new().case_sensitive(true);
+    assert_eq!(parse("4 KiB", &case_sensitive), Ok(MemorySize::from_bytes(4096)));
+    assert_eq!(parse("4 kB", &case_sensitive), Ok(MemorySize::from_bytes(4000)));
+    assert_eq!(parse("4 Mbit", &case_sensitive), Ok(MemorySize::from_bits(4_000_000)));
+    assert_eq!(parse("4 megabytes", &case_sensitive), Ok(MemorySize::from_bytes(4_000_000)));
+    for (text, suggestion) in [("4 KB", "kB"), ("4 kiB", "KiB"), ("4 mB", "MB"), ("4 KIB", "KiB"), ("4 Megabytes", "megabytes"), ("4 BIT", "bit")] {
+        assert_eq!(parse(text, &case_sensitive), Err(MemorySizeParseError::UnknownUnit { offset: 2, suggestion: Some(suggestion) }), "parsing {:?}", text);
+        assert!(text.parse::<MemorySize>().is_ok());
+    }
+
+    let bits = ParseOptions::new().bare_numbers_are_bits(true);
+    assert_eq!(parse("12", &bits), Ok(MemorySize::from_bits(12)));
+    assert_eq!(parse("1 B + 1", &bits), Ok(MemorySize::from_bits(9)));
+
+    let jedec = ParseOptions::new().si_as_binary(true);
+    assert_eq!(parse("1 kB", &jedec), Ok(MemorySize::from_bytes(1024)));
+    assert_eq!(parse("2 gigabytes", &jedec), Ok(MemorySize::from_bytes(2 << 30)));
+
+    let whole = ParseOptions::new().reject_fractional_bytes(true);
+    assert_eq!(parse("1.5 KiB", &whole), Ok(MemorySize::from_bytes(1536)));
+    assert_eq!(parse("16 bit", &whole), Ok(MemorySize::from_bytes(2)));
+    assert_eq!(parse("1 KiB 0.1 KiB", &whole), Err(MemorySizeParseError::FractionalBytes { offset: 6 }));
+    assert_eq!(parse("12 bit", &whole), Err(MemorySizeParseError::FractionalBytes { offset: 0 }));
+}
+
+#[test]
+fn parse_const_matches_from_str() {
+    let texts = [
+        "0", "42", " 7B ", "1.5 B", "0.1 KiB", ".5 KiB", "1.", "2 kB", "2 KIB", "1 EiB", "100 Mbit", "8 Kib",
+        "3 bits", "12bit", "1.5 bit", "1 byte", "2 EiB", "", "KiB", "1.2.3", "1 kilobyte", "1 XB", "1 KiBs", "1 k",
+        "0x1000", "0X1f KiB", "0x1B", "0b101 bit", "0x", "0b", "0x1.5", "0xffffffffffffffffffff",
+        "4 BIT", "2 Bytes", "+4 KiB", "+ 1 MB", "1 Byte", "34028236692093846346.3374607431768211455 bit",
+        //39 digits, where only the final digit overflows `u128`
+        "340282366920938463463374607431768211455 bit", "340282366920938463463374607431768211456 bit",
+    ];
+    for text in texts {
+        let parsed = text.parse::<MemorySize>().ok().filter(|_| !text.contains("kilo"));
+        assert_eq!(MemorySize::parse_const(text), parsed, "parsing {:?}", text);
+    }
+
+    const LIMIT: MemorySize = crate::memsize!("16 GiB");
+    assert_eq!(LIMIT, MemorySize::from_bytes(16 << 30));
+}
+
+#[cfg(feature = "clap")]
+#[test]
+fn clap_value_parser() {
+    use clap::{value_parser, Arg, Command};
+
+    let cmd = Command::new("app").arg(Arg::new("cache").long("cache").value_parser(value_parser!(MemorySize)));
+
+    let matches = cmd.clone().try_get_matches_from(["app", "--cache", "1 GiB 512 MiB"]).unwrap();
+    assert_eq!(matches.get_one::<MemorySize>("cache"), Some(&MemorySize::from_bytes(3 << 29)));
+
+    let err = cmd.try_get_matches_from(["app", "--cache", "12 parsecs"]).unwrap_err();
+    assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("invalid value '12 parsecs' for '--cache <cache>': failed to parse size: unknown unit at byte 3"), "{}", err);
+}
+
+#[test]
+fn from_env_errors() {
+    use crate::{FromEnvError, MemorySizeParseError};
+
+    let default = MemorySize::from_bytes(1);
+    std::env::set_var("MEMORY_SIZE_TEST_INVALID", "lots");
+    let err = MemorySize::from_env_or("MEMORY_SIZE_TEST_INVALID", default).unwrap_err();
+    assert_eq!(err, FromEnvError::Invalid {
+        var: "MEMORY_SIZE_TEST_INVALID".to_string(),
+        value: "lots".to_string(),
+        error: MemorySizeParseError::InvalidNumber { offset: 0 },
+    });
+    assert_eq!(err.to_string(), "environment variable MEMORY_SIZE_TEST_INVALID has the invalid size \"lots\": invalid number at byte 0");
+
+    assert_eq!(
+        MemorySize::from_env("MEMORY_SIZE_TEST_UNSET").unwrap_err().to_string(),
+        "environment variable MEMORY_SIZE_TEST_UNSET is not set"
+    );
+    std::env::set_var("MEMORY_SIZE_TEST_VALID", "2 KiB");
+    assert_eq!(MemorySize::from_env_or("MEMORY_SIZE_TEST_VALID", default), Ok(MemorySize::from_bytes(2048)));
+}
+
+#[test]
+fn parse_hex_and_binary() {
+    use crate::MemorySizeParseError;
+
+    let cases = [
+        ("0x1000", MemorySize::from_bytes(4096)),
+        ("0x1000 B", MemorySize::from_bytes(4096)),
+        ("0X200000", MemorySize::from_bytes(2 << 20)),
+        ("0xff KiB", MemorySize::from_bytes(255 << 10)),
+        ("0x10KiB", MemorySize::from_bytes(16 << 10)),
+        ("0b1010 bit", MemorySize::from_bits(10)),
+        ("0x1B", MemorySize::from_bytes(27)),
+        //a zero followed by bits
+        ("0b", MemorySize::new()),
+        ("0x1000 B + 0b1", MemorySize::from_bytes(4097)),
+    ];
+    for (text, size) in cases {
+        assert_eq!(text.parse(), Ok(size), "parsing {:?}", text);
+    }
+
+    assert_eq!("0x".parse::<MemorySize>(), Err(MemorySizeParseError::UnknownUnit { offset: 1, suggestion: None }));
+    assert_eq!("0x1.5".parse::<MemorySize>(), Err(MemorySizeParseError::UnknownUnit { offset: 3, suggestion: None }));
+    assert_eq!("0x1_0000_0000_0000_0000".parse::<MemorySize>().unwrap_err().offset(), 3);
+    assert_eq!("0x2000000000000000".parse::<MemorySize>(), Err(MemorySizeParseError::Overflow { offset: 0 }));
+}
+
+#[test]
+fn display_with_options() {
+    use crate::format::{FormatOptions, UnitSystem, MAX_PRECISION};
+
+    let si = FormatOptions::new().units(UnitSystem::Si);
+    let display = |bytes: u64, options| MemorySize::from_bytes(bytes).display_with(options).to_string();
+
+    assert_eq!(display(999, si), "999 B");
+    assert_eq!(display(1000, si), "1.00 kB");
+    assert_eq!(display(999_999, si), "1.00 MB");
+    assert_eq!(display(1_500_000_000, si.precision(0)), "2 GB");
+    assert_eq!(display(1 << 20, FormatOptions::new().precision(4)), "1.0000 MiB");
+    assert_eq!(display(1000, si.precision(0)), "1 kB");
+    assert_eq!(MemorySize::from_bits(u64::MAX).display_with(si).to_string(), "2.31 EB");
+    assert_eq!(
+        display(3, FormatOptions::new().precision(100)),
+        display(3, FormatOptions::new().precision(MAX_PRECISION))
+    );
+    assert_eq!(MemorySize::from_bytes(1000).display_with(si.precision(0)).with_template("{value} {unit_long}").to_string(), "1 kilobyte");
+}
+
+#[test]
+fn display_formatter_flags() {
+    let size = MemorySize::from_bytes(1536);
+
+    assert_eq!(format!("{:12}", size), "    1.50 KiB");
+    assert_eq!(format!("{:<12}|", size), "1.50 KiB    |");
+    assert_eq!(format!("{:^12}", size), "  1.50 KiB  ");
+    assert_eq!(format!("{:*>10}", size), "**1.50 KiB");
+    assert_eq!(format!("{:010}", size), "001.50 KiB");
+    assert_eq!(format!("{:.0}", size), "2 KiB");
+    assert_eq!(format!("{:.3}", size), "1.500 KiB");
+    //narrower widths never truncate
+    assert_eq!(format!("{:3}", size), "1.50 KiB");
+    assert_eq!(format!("{:>6}", MemorySize::from_bits(3)), " 3 bit");
+    assert_eq!(format!("{:>6}", MemorySize::from_bytes(4096).display_round_trip()), " 4 KiB");
+    assert_eq!(format!("{:.1}", MemorySize::from_bytes(512)), "512 B");
+}
+
+#[test]
+fn display_alternate_exact() {
+    assert_eq!(format!("{:#}", MemorySize::from_bytes(1 << 20)), "1048576 B");
+    assert_eq!(format!("{:#}", MemorySize::from_bits(8192)), "1024 B");
+    assert_eq!(format!("{:#}", MemorySize::from_bits(8191)), "8191 bits");
+    assert_eq!(format!("{:#}", MemorySize::from_bits(1)), "1 bit");
+    assert_eq!(format!("{:#}", MemorySize::new()), "0 B");
+    assert_eq!(format!("{:*>#12}", MemorySize::from_bytes(4097)), "******4097 B");
+    //the non-alternate form stays human-scaled
+    assert_eq!(format!("{}", MemorySize::from_bytes(1 << 20)), "1.00 MiB");
+    //exact output parses back to the same size
+    let size = MemorySize::from_bits(8191);
+    assert_eq!(format!("{:#}", size).parse::<MemorySize>().unwrap(), size);
+}
+
+#[test]
+fn display_bit_units() {
+    use crate::format::{FormatOptions, UnitSystem};
+
+    assert_eq!(MemorySize::from_bits(100_000_000).display_bits().to_string(), "100.00 Mbit");
+    assert_eq!(MemorySize::from_bits(1_500_000_000).display_bits().to_string(), "1.50 Gbit");
+    assert_eq!(MemorySize::from_bits(999).display_bits().to_string(), "999 bit");
+    assert_eq!(MemorySize::from_bits(1).display_bits().with_template("{value} {unit_long}").to_string(), "1 bit");
+    assert_eq!(MemorySize::from_bits(2_000).display_bits().with_template("{value} {unit_long}").to_string(), "2.00 kilobits");
+    assert_eq!(MemorySize::from_bits(999_999).display_bits().to_string(), "1.00 Mbit");
+
+    let iec = FormatOptions::new().units(UnitSystem::Iec).bits(true).precision(1);
+    assert_eq!(MemorySize::from_bits(1536).display_with(iec).to_string(), "1.5 Kibit");
+    assert_eq!(MemorySize::from_bits(u64::MAX).display_with(iec).to_string(), "16.0 Eibit");
+
+    //bit units parse back
+    let size = MemorySize::from_bits(3_000_000);
+    assert_eq!(size.display_bits().to_string().parse::<MemorySize>().unwrap(), size);
+}
+
+#[test]
+fn display_adapters() {
+    let size = MemorySize::from_bytes(1_500_000);
+
+    assert_eq!(size.display_iec().to_string(), "1.43 MiB");
+    assert_eq!(size.display_si().to_string(), "1.50 MB");
+    assert_eq!(size.display_exact().to_string(), "1500000 B");
+    assert_eq!(size.display_iec().to_string(), size.to_string());
+    assert_eq!(size.display_exact().to_string(), format!("{:#}", size));
+    assert_eq!(format!("{:>12}", size.display_exact()), "   1500000 B");
+    assert_eq!(format!("{:.0}", size.display_si()), "2 MB");
+}
+
+#[cfg(feature = "i18n")]
+#[test]
+fn display_localized() {
+    use crate::format::{FormatOptions, UnitSystem};
+    use crate::i18n::Locale;
+
+    let french = FormatOptions::new().locale(&Locale::FRENCH);
+    assert_eq!(MemorySize::from_bytes(1536).display_with(french).to_string(), "1,50 Kio");
+    assert_eq!(MemorySize::from_bytes(512).display_with(french).to_string(), "512 o");
+    assert_eq!(MemorySize::from_bits(3).display_with(french).to_string(), "3 bit");
+    assert_eq!(MemorySize::from_bytes(2_000_000).display_with(french.units(UnitSystem::Si)).with_template("{value} {unit_long}").to_string(), "2,00 mégaoctets");
+
+    let russian = FormatOptions::new().locale(&Locale::RUSSIAN).units(UnitSystem::Si).bits(true).precision(1);
+    assert_eq!(MemorySize::from_bits(100_000_000).display_with(russian).to_string(), "100,0 Мбит");
+    assert_eq!(MemorySize::from_bytes(1536).display_with(FormatOptions::new().locale(&Locale::RUSSIAN)).with_template("{value}{unit_lower}").to_string(), "1,50киб");
+
+    //english is the default
+    assert_eq!(MemorySize::from_bytes(1536).display_with(FormatOptions::new().locale(&Locale::ENGLISH)).to_string(), "1.50 KiB");
+}
+
+#[test]
+fn display_exact_grouped() {
+    let grouped = |size: MemorySize| size.display_exact().grouped(',').to_string();
+
+    assert_eq!(grouped(MemorySize::from_bytes(1 << 20)), "1,048,576 B");
+    assert_eq!(grouped(MemorySize::from_bytes(999)), "999 B");
+    assert_eq!(grouped(MemorySize::from_bytes(1000)), "1,000 B");
+    assert_eq!(grouped(MemorySize::from_bytes(1_000_005)), "1,000,005 B");
+    assert_eq!(grouped(MemorySize::new()), "0 B");
+    assert_eq!(grouped(MemorySize::from_bits(12_345)), "12,345 bits");
+    assert_eq!(grouped(MemorySize::from_bits(u64::MAX)), "18,446,744,073,709,551,615 bits");
+    assert_eq!(format!("{:>12}", MemorySize::from_bytes(1 << 20).display_exact().grouped('_')), " 1_048_576 B");
+}
+
+#[test]
+fn display_radix() {
+    let page = MemorySize::from_bytes(4096);
+
+    assert_eq!(format!("{:x}", page), "1000");
+    assert_eq!(format!("{:#x}", page), "0x1000");
+    assert_eq!(format!("{:#010X}", MemorySize::from_bytes(0xBEEF)), "0x0000BEEF");
+    assert_eq!(format!("{:#b}", MemorySize::from_bytes(5)), "0b101");
+    assert_eq!(format!("{:o}", MemorySize::from_bytes(8)), "10");
+    assert_eq!(format!("{:#x}", MemorySize::from_bits(12)), "0xc bits");
+}
+
+#[test]
+fn debug_alternate_human() {
+    assert_eq!(format!("{:?}", MemorySize::from_bytes(1024)), "MemorySize { size_bits: 8192 }");
+    assert_eq!(format!("{:#?}", MemorySize::from_bytes(1024)), "MemorySize { size_bits: 8192 /* 1.00 KiB */ }");
+    assert_eq!(format!("{:#?}", MemorySize::from_bits(3)), "MemorySize { size_bits: 3 /* 3 bit */ }");
+    assert_eq!(format!("{:#?}", Some(MemorySize::from_bytes(1536))), "Some(\n    MemorySize { size_bits: 12288 /* 1.50 KiB */ },\n)");
+}
+
+#[test]
+fn decompose_units() {
+    let size = MemorySize::from_bytes((3 << 29) + 12) + MemorySize::from_bits(5);
+    let parts = size.decompose();
+    assert_eq!((parts.eib, parts.pib, parts.tib, parts.gib, parts.mib, parts.kib, parts.bytes, parts.bits), (0, 0, 0, 1, 512, 0, 12, 5));
+    assert_eq!(parts.to_string(), "1 GiB 512 MiB 12 B 5 bit");
+
+    assert_eq!(MemorySize::new().decompose(), crate::Decomposed::default());
+    assert_eq!(MemorySize::new().decompose().to_string(), "0 B");
+
+    let max = MemorySize::from_bits(u64::MAX).decompose();
+    assert_eq!((max.eib, max.pib, max.bytes, max.bits), (1, 1023, 1023, 7));
+    assert_eq!(max.to_string().parse::<MemorySize>().unwrap(), MemorySize::from_bits(u64::MAX));
+}
+
+#[test]
+fn display_progress() {
+    use crate::format::{FormatOptions, UnitSystem};
+
+    let gib = MemorySize::from_bytes(1 << 30);
+    assert_eq!(MemorySize::from_bytes(512 << 20).progress(gib).to_string(), "0.50 GiB / 1.00 GiB (50.0%)");
+    assert_eq!(MemorySize::new().progress(gib).to_string(), "0.00 GiB / 1.00 GiB (0.0%)");
+    assert_eq!(MemorySize::from_bytes(3 << 30).progress(gib).to_string(), "3.00 GiB / 1.00 GiB (300.0%)");
+    assert_eq!(MemorySize::from_bytes(100).progress(MemorySize::from_bytes(300)).to_string(), "100 B / 300 B (33.3%)");
+    assert_eq!(MemorySize::new().progress(MemorySize::new()).to_string(), "0 B / 0 B");
+
+    let si = FormatOptions::new().units(UnitSystem::Si).precision(1);
+    let progress = MemorySize::from_bytes(250_000).progress(MemorySize::from_bytes(2_000_000)).with_options(si);
+    assert_eq!(progress.to_string(), "0.3 MB / 2.0 MB (12.5%)");
+    assert_eq!(format!("{:>28}", progress), "     0.3 MB / 2.0 MB (12.5%)");
+}
+
+#[test]
+fn format_column() {
+    use crate::format::{column, FormatOptions, UnitSystem};
+
+    let sizes = [MemorySize::from_bytes(3), MemorySize::from_bytes(1536), MemorySize::from_bytes(1288490189)];
+    assert_eq!(column(&sizes, FormatOptions::new(), false), ["     3 B", "1.50 KiB", "1.20 GiB"]);
+    assert_eq!(column(&sizes, FormatOptions::new(), true), ["0.00 GiB", "0.00 GiB", "1.20 GiB"]);
+
+    let si = FormatOptions::new().units(UnitSystem::Si).precision(0);
+    let sizes = [MemorySize::from_bytes(5_000), MemorySize::from_bytes(120_000)];
+    assert_eq!(column(&sizes, si, true), ["  5 kB", "120 kB"]);
+
+    assert!(column(&[], FormatOptions::new(), true).is_empty());
+}
+
+#[test]
+fn display_exponent() {
+    let mib = MemorySize::from_bytes(1 << 20);
+
+    assert_eq!(format!("{:e}", mib), "1.048576e6 B");
+    assert_eq!(format!("{:.4e}", mib), "1.0486e6 B");
+    assert_eq!(format!("{:.2E}", mib), "1.05E6 B");
+    assert_eq!(format!("{:e}", MemorySize::from_bits(12)), "1.2e1 bits");
+    assert_eq!(format!("{:e}", MemorySize::new()), "0e0 B");
+    assert_eq!(format!("{:>12.1e}", mib), "     1.0e6 B");
+}
+
+#[test]
+fn display_delta() {
+    let delta = |before: u64, after: u64| MemorySize::delta_display(MemorySize::from_bytes(before), MemorySize::from_bytes(after)).to_string();
+
+    assert_eq!(delta(4 << 20, (33 << 20) / 2), "+12.50 MiB");
+    assert_eq!(delta(4096, 1024), "-3.00 KiB");
+    assert_eq!(delta(100, 100), "+0 B");
+    assert_eq!(delta(0, 12), "+12 B");
+    assert_eq!(MemorySize::delta_display(MemorySize::from_bits(9), MemorySize::new()).to_string(), "-9 bit");
+    assert_eq!(MemorySize::delta_display(MemorySize::new(), MemorySize::from_bits(u64::MAX)).to_string(), "+2.00 EiB");
+    assert_eq!(format!("{:>10}", MemorySize::delta_display(MemorySize::from_bytes(2048), MemorySize::new())), " -2.00 KiB");
+}
+
+#[test]
+fn display_sectors_blocks() {
+    assert_eq!(MemorySize::from_bytes(1 << 20).display_sectors().to_string(), "2048 sectors");
+    assert_eq!(MemorySize::from_bytes(513).display_sectors().to_string(), "2 sectors");
+    assert_eq!(MemorySize::new().display_sectors().to_string(), "0 sectors");
+    assert_eq!(MemorySize::from_bits(1).display_sectors().to_string(), "1 sector");
+
+    let block = MemorySize::from_bytes(4096);
+    assert_eq!(MemorySize::from_bytes(1 << 20).display_blocks(block).to_string(), "256 blocks @4KiB");
+    assert_eq!(MemorySize::from_bytes(4096).display_blocks(block).to_string(), "1 block @4KiB");
+    assert_eq!(MemorySize::from_bytes(3000).display_blocks(MemorySize::from_bytes(1000)).to_string(), "3 blocks @1000B");
+    assert_eq!(format!("{:>14}", MemorySize::from_bytes(1 << 20).display_sectors()), "  2048 sectors");
+}
+
+#[test]
+fn checked_arithmetic() {
+    let max = MemorySize::from_bits(u64::MAX);
+
+    assert_eq!(MemorySize::from_bytes(1).checked_add(MemorySize::from_bits(1)), Some(MemorySize::from_bits(9)));
+    assert_eq!(max.checked_add(MemorySize::new()), Some(max));
+    assert_eq!(max.checked_add(MemorySize::from_bits(1)), None);
+
+    assert_eq!(MemorySize::from_bytes(2).checked_sub(MemorySize::from_bytes(2)), Some(MemorySize::new()));
+    assert_eq!(MemorySize::new().checked_sub(MemorySize::from_bits(1)), None);
+
+    assert_eq!(MemorySize::from_bytes(3).checked_mul(0), Some(MemorySize::new()));
+    assert_eq!(MemorySize::from_bits(u64::MAX / 2).checked_mul(2), Some(MemorySize::from_bits(u64::MAX - 1)));
+    assert_eq!(MemorySize::from_bits(u64::MAX / 2 + 1).checked_mul(2), None);
+}
+
+#[test]
+fn saturating_arithmetic() {
+    let max = MemorySize::from_bits(u64::MAX);
+
+    assert_eq!(MemorySize::from_bytes(1).saturating_add(MemorySize::from_bits(1)), MemorySize::from_bits(9));
+    assert_eq!(max.saturating_add(max), max);
+
+    assert_eq!(MemorySize::from_bytes(2).saturating_sub(MemorySize::from_bits(1)), MemorySize::from_bits(15));
+    assert_eq!(MemorySize::new().saturating_sub(max), MemorySize::new());
+
+    assert_eq!(MemorySize::from_bytes(3).saturating_mul(0), MemorySize::new());
+    assert_eq!(MemorySize::from_bits(u64::MAX / 2 + 1).saturating_mul(2), max);
+}
+
+#[test]
+fn overflowing_wrapping_arithmetic() {
+    let max = MemorySize::from_bits(u64::MAX);
+
+    assert_eq!(max.overflowing_add(MemorySize::new()), (max, false));
+    assert_eq!(max.overflowing_add(max), (MemorySize::from_bits(u64::MAX - 1), true));
+    assert_eq!(MemorySize::from_bits(5).overflowing_sub(MemorySize::from_bits(5)), (MemorySize::new(), false));
+    assert_eq!(MemorySize::from_bits(1).overflowing_sub(MemorySize::from_bits(3)), (MemorySize::from_bits(u64::MAX - 1), true));
+    assert_eq!(max.overflowing_mul(1), (max, false));
+    assert_eq!(max.overflowing_mul(3), (MemorySize::from_bits(u64::MAX - 2), true));
+
+    //wrapping matches the size from overflowing
+    let (a, b) = (MemorySize::from_bits(u64::MAX - 7), MemorySize::from_bytes(3));
+    assert_eq!(a.wrapping_add(b), a.overflowing_add(b).0);
+    assert_eq!(b.wrapping_sub(a), b.overflowing_sub(a).0);
+    assert_eq!(a.wrapping_mul(5), a.overflowing_mul(5).0);
+}
+
+#[test]
+fn scalar_mul_div() {
+    let element = MemorySize::from_bytes(24);
+
+    assert_eq!(element * 100, MemorySize::from_bytes(2400));
+    assert_eq!(element * 1, element);
+    assert_eq!(MemorySize::from_bytes(2400) / 100, element);
+    assert_eq!(MemorySize::from_bytes(1) / 3, MemorySize::from_bits(2));
+}
+
+#[test]
+#[should_panic]
+fn scalar_mul_panic() {
+    let _ = MemorySize::from_bits(u64::MAX / 2 + 1) * 2;
+}
+
+#[test]
+fn size_ratio() {
+    let page = MemorySize::from_bytes(4096);
+
+    assert_eq!(MemorySize::from_bytes(1 << 20) / page, 256);
+    assert_eq!(MemorySize::from_bytes(4095) / page, 0);
+    assert_eq!(MemorySize::from_bits(u64::MAX) / MemorySize::from_bits(1), u64::MAX);
+
+    assert_eq!(MemorySize::from_bytes(1024).as_ratio_f64(page), 0.25);
+    assert_eq!(page.as_ratio_f64(MemorySize::new()), f64::INFINITY);
+    assert!(MemorySize::new().as_ratio_f64(MemorySize::new()).is_nan());
+}
+
+#[test]
+fn size_remainder() {
+    let chunk = MemorySize::from_bytes(12);
+
+    assert_eq!(MemorySize::from_bytes(100) % chunk, MemorySize::from_bytes(4));
+    assert_eq!(MemorySize::from_bytes(96) % chunk, MemorySize::new());
+    assert_eq!(MemorySize::from_bits(99) % MemorySize::from_bytes(1), MemorySize::from_bits(3));
+    assert_eq!(MemorySize::from_bytes(100).checked_rem(chunk), Some(MemorySize::from_bytes(4)));
+    assert_eq!(chunk.checked_rem(MemorySize::new()), None);
+}
+
+#[test]
+#[should_panic]
+fn size_remainder_panic() {
+    let _ = MemorySize::from_bytes(100) % MemorySize::new();
+}
+
+#[test]
+fn scalar_mul_div_assign() {
+    let mut size = MemorySize::from_bytes(3);
+    size *= 4;
+    assert_eq!(size, MemorySize::from_bytes(12));
+    size /= 5;
+    assert_eq!(size, MemorySize::from_bits(19));
+}
+
+#[test]
+fn size_abs_diff() {
+    let max = MemorySize::from_bits(u64::MAX);
+
+    assert_eq!(MemorySize::from_bytes(3).abs_diff(MemorySize::from_bits(4)), MemorySize::from_bits(20));
+    assert_eq!(MemorySize::from_bits(4).abs_diff(MemorySize::from_bytes(3)), MemorySize::from_bits(20));
+    assert_eq!(max.abs_diff(max), MemorySize::new());
+    assert_eq!(MemorySize::new().abs_diff(max), max);
+}
+
+#[test]
+fn signed_memory_size() {
+    use crate::SignedMemorySize;
+
+    let before = MemorySize::from_bytes(4096);
+    let after = MemorySize::from_bytes(1024);
+    let shrink = after.signed_diff(before);
+
+    assert_eq!(shrink, SignedMemorySize::from_bytes(-3072));
+    assert!(shrink.is_negative());
+    assert_eq!(-shrink, before.signed_diff(after));
+    assert_eq!(shrink + SignedMemorySize::from_bytes(3072), SignedMemorySize::new());
+    assert_eq!(shrink - shrink, SignedMemorySize::new());
+    assert_eq!([shrink, -shrink, shrink].into_iter().sum::<SignedMemorySize>(), shrink);
+
+    assert_eq!(MemorySize::try_from(-shrink), Ok(MemorySize::from_bytes(3072)));
+    assert_eq!(MemorySize::try_from(shrink), Err(crate::MemorySizeError::Negative));
+    assert_eq!(SignedMemorySize::try_from(before), Ok(SignedMemorySize::from_bytes(4096)));
+    assert_eq!(SignedMemorySize::try_from(MemorySize::from_bits(u64::MAX)), Err(crate::MemorySizeError::Overflow));
+
+    assert_eq!(shrink.to_string(), "-3.00 KiB");
+    assert_eq!(format!("{:>10.1}", shrink), "  -3.0 KiB");
+    assert_eq!(format!("{:#}", shrink), "-3072 B");
+    assert_eq!(MemorySize::new().signed_diff(MemorySize::from_bits(1 << 63)).size_bits(), i64::MIN);
+}
+
+#[test]
+#[should_panic]
+fn signed_diff_panic() {
+    MemorySize::from_bits(u64::MAX).signed_diff(MemorySize::new());
+}
+
+#[test]
+fn saturating_wrapper() {
+    use crate::Saturating;
+
+    let max = MemorySize::from_bits(u64::MAX);
+    let mut total = Saturating(MemorySize::from_bytes(1));
+
+    total += Saturating(MemorySize::from_bytes(2));
+    assert_eq!(total.0, MemorySize::from_bytes(3));
+    total -= Saturating(MemorySize::from_bytes(5));
+    assert_eq!(total, Saturating(MemorySize::new()));
+    total += Saturating(max);
+    total += Saturating(max);
+    assert_eq!(total.0, max);
+    total *= 0;
+    assert_eq!(total.0, MemorySize::new());
+    assert_eq!((Saturating(max) * 2).0, max);
+
+    let sum: Saturating<MemorySize> = [max, max, MemorySize::from_bits(1)].into_iter().map(Saturating).sum();
+    assert_eq!(sum.0, max);
+    assert_eq!(Saturating(MemorySize::from_bytes(1536)).to_string(), "1.50 KiB");
+}
+
+#[test]
+fn scale_rational() {
+    use crate::RoundingMode;
+
+    let size = MemorySize::from_bits(10);
+    assert_eq!(size.scale(1, 4, RoundingMode::Floor), MemorySize::from_bits(2));
+    assert_eq!(size.scale(1, 4, RoundingMode::Ceil), MemorySize::from_bits(3));
+    assert_eq!(size.scale(1, 4, RoundingMode::Nearest), MemorySize::from_bits(3));
+    assert_eq!(size.scale(1, 5, RoundingMode::Ceil), MemorySize::from_bits(2));
+    assert_eq!(size.scale(0, 5, RoundingMode::Ceil), MemorySize::new());
+
+    //intermediate results larger than 64 bits do not overflow
+    let max = MemorySize::from_bits(u64::MAX);
+    assert_eq!(max.scale(u64::MAX, u64::MAX, RoundingMode::Floor), max);
+    assert_eq!(max.scale(3, 4, RoundingMode::Floor), MemorySize::from_bits(u64::MAX / 4 * 3 + 2));
+}
+
+#[test]
+#[should_panic]
+fn scale_overflow_panic() {
+    MemorySize::from_bits(u64::MAX).scale(2, 1, crate::RoundingMode::Floor);
+}
+
+#[test]
+fn mul_f64_rounding() {
+    use crate::{MemorySizeError, RoundingMode};
+
+    let size = MemorySize::from_bits(10);
+    assert_eq!(size.mul_f64(0.25, RoundingMode::Floor), Ok(MemorySize::from_bits(2)));
+    assert_eq!(size.mul_f64(0.25, RoundingMode::Ceil), Ok(MemorySize::from_bits(3)));
+    assert_eq!(size.mul_f64(0.25, RoundingMode::Nearest), Ok(MemorySize::from_bits(3)));
+    assert_eq!(size.mul_f64(0.24, RoundingMode::Nearest), Ok(MemorySize::from_bits(2)));
+    assert_eq!(size.mul_f64(0.0, RoundingMode::Ceil), Ok(MemorySize::new()));
+    assert_eq!(MemorySize::new().mul_f64(-2.0, RoundingMode::Floor), Ok(MemorySize::new()));
+
+    assert_eq!(size.mul_f64(f64::NAN, RoundingMode::Floor), Err(MemorySizeError::InvalidFactor));
+    assert_eq!(size.mul_f64(f64::INFINITY, RoundingMode::Floor), Err(MemorySizeError::InvalidFactor));
+    assert_eq!(size.mul_f64(-0.5, RoundingMode::Floor), Err(MemorySizeError::Negative));
+    assert_eq!(MemorySize::from_bits(u64::MAX).mul_f64(1.0, RoundingMode::Floor), Err(MemorySizeError::Overflow));
+    assert_eq!(MemorySize::from_bits(1 << 62).mul_f64(2.0, RoundingMode::Floor), Ok(MemorySize::from_bits(1 << 63)));
+}
+
+#[test]
+fn shift_operators() {
+    let size = MemorySize::from_bytes(1);
+
+    assert_eq!(size << 10, MemorySize::from_bytes(1024));
+    assert_eq!(size << 0, size);
+    assert_eq!(MemorySize::from_bytes(1024) >> 10, size);
+    assert_eq!(MemorySize::from_bits(7) >> 1, MemorySize::from_bits(3));
+
+    assert_eq!(MemorySize::from_bits(1 << 62).checked_shl(1), Some(MemorySize::from_bits(1 << 63)));
+    assert_eq!(MemorySize::from_bits(3 << 62).checked_shl(1), None);
+    assert_eq!(MemorySize::new().checked_shl(100), Some(MemorySize::new()));
+    assert_eq!(size.checked_shl(64), None);
+    assert_eq!(MemorySize::from_bits(u64::MAX).checked_shr(63), Some(MemorySize::from_bits(1)));
+}
+
+#[test]
+#[should_panic]
+fn shift_left_panic() {
+    let _ = MemorySize::from_bits(u64::MAX) << 1;
+}
+
+#[test]
+fn power_of_two_utilities() {
+    assert!(MemorySize::from_bits(1).is_power_of_two());
+    assert!(MemorySize::from_bits(1 << 63).is_power_of_two());
+    assert!(!MemorySize::from_bits(3).is_power_of_two());
+
+    assert_eq!(MemorySize::new().next_power_of_two(), MemorySize::from_bits(1));
+    assert_eq!(MemorySize::from_bits(5).next_power_of_two(), MemorySize::from_bits(8));
+    assert_eq!(MemorySize::from_bits((1 << 63) + 1).checked_next_power_of_two(), None);
+
+    assert_eq!(MemorySize::from_bits(1).prev_power_of_two(), MemorySize::from_bits(1));
+    assert_eq!(MemorySize::from_bits(u64::MAX).prev_power_of_two(), MemorySize::from_bits(1 << 63));
+    assert_eq!(MemorySize::from_bytes(1000).prev_power_of_two(), MemorySize::from_bytes(512));
+
+    assert_eq!(MemorySize::from_bits(1).ilog2(), 0);
+    assert_eq!(MemorySize::from_bits(u64::MAX).ilog2(), 63);
+    assert_eq!(MemorySize::from_bytes(1).ilog2_bytes(), 0);
+    assert_eq!(MemorySize::from_bits(15).ilog2_bytes(), 0);
+}
+
+#[test]
+#[should_panic]
+fn ilog2_zero_panic() {
+    MemorySize::new().ilog2();
+}
+
+#[test]
+fn block_div_ceil() {
+    let block = MemorySize::from_bytes(512);
+
+    assert_eq!(MemorySize::new().div_ceil(block), 0);
+    assert_eq!(MemorySize::from_bits(1).div_ceil(block), 1);
+    assert_eq!(MemorySize::from_bytes(1024).div_ceil(block), 2);
+    assert_eq!(MemorySize::from_bits(u64::MAX).div_ceil(MemorySize::from_bits(2)), u64::MAX / 2 + 1);
+}
+
+#[test]
+fn align_up_near_max() {
+    let eight = MemorySize::from_bits(8);
+    let top = MemorySize::from_bits(u64::MAX - 7);
+
+    //already aligned sizes near the top no longer overflow while rounding
+    assert_eq!(top.align_up(eight), top);
+    assert_eq!(MemorySize::from_bits(u64::MAX - 8).align_up(eight), top);
+    assert_eq!(MemorySize::from_bits(u64::MAX).checked_align_up(MemorySize::from_bits(u64::MAX)), Some(MemorySize::from_bits(u64::MAX)));
+    assert_eq!(MemorySize::from_bits(u64::MAX - 6).checked_align_up(eight), None);
+    assert_eq!(MemorySize::from_bits(5).checked_align_up(MemorySize::new()), Some(MemorySize::from_bits(5)));
+    assert_eq!(MemorySize::new().checked_align_up(eight), Some(MemorySize::new()));
+}
+
+#[test]
+#[should_panic]
+fn align_up_overflow_panic() {
+    MemorySize::from_bits(u64::MAX).align_up(MemorySize::from_bits(2));
+}
+
+#[test]
+fn align_down_sizes() {
+    let four = MemorySize::from_bytes(4);
+
+    assert_eq!(MemorySize::from_bytes(27).align_down(four), MemorySize::from_bytes(24));
+    assert_eq!(MemorySize::from_bits(31).align_down(four), MemorySize::new());
+    assert_eq!(MemorySize::from_bits(u64::MAX).align_down(MemorySize::from_bits(8)), MemorySize::from_bits(u64::MAX - 7));
+    assert_eq!(MemorySize::from_bits(7).align_down(MemorySize::new()), MemorySize::from_bits(7));
+    assert!(MemorySize::from_bytes(27).align_down(four) <= MemorySize::from_bytes(27).align_up(four));
+}
+
+#[test]
+fn align_nearest_sizes() {
+    let four = MemorySize::from_bits(4);
+    let three = MemorySize::from_bits(3);
+
+    assert_eq!(MemorySize::from_bits(5).align_nearest(four), MemorySize::from_bits(4));
+    assert_eq!(MemorySize::from_bits(6).align_nearest(four), MemorySize::from_bits(8));
+    assert_eq!(MemorySize::from_bits(8).align_nearest(four), MemorySize::from_bits(8));
+    assert_eq!(MemorySize::from_bits(4).align_nearest(three), MemorySize::from_bits(3));
+    assert_eq!(MemorySize::from_bits(5).align_nearest(three), MemorySize::from_bits(6));
+    let top = MemorySize::from_bits(u64::MAX - u32::MAX as u64);
+    assert_eq!(MemorySize::from_bits(top.size_bits() + 5).align_nearest(MemorySize::from_bits(1 << 32)), top);
+    assert_eq!(MemorySize::from_bits(5).align_nearest(MemorySize::new()), MemorySize::from_bits(5));
+}
+
+#[test]
+fn padding_needed() {
+    let four = MemorySize::from_bytes(4);
+
+    assert_eq!(MemorySize::from_bits(1).padding_needed_for(four), MemorySize::from_bits(31));
+    assert_eq!(MemorySize::new().padding_needed_for(four), MemorySize::new());
+    assert_eq!(MemorySize::from_bits(5).padding_needed_for(MemorySize::new()), MemorySize::new());
+    //the padded size would overflow, but the padding itself does not
+    assert_eq!(MemorySize::from_bits(u64::MAX).padding_needed_for(four), MemorySize::from_bits(1));
+
+    let size = MemorySize::from_bytes(13);
+    assert_eq!(size + size.padding_needed_for(four), size.align_up(four));
+}
+
+#[test]
+fn natural_alignment_of_sizes() {
+    assert_eq!(MemorySize::from_bits(1).natural_alignment(), MemorySize::from_bits(1));
+    assert_eq!(MemorySize::from_bits(u64::MAX).natural_alignment(), MemorySize::from_bits(1));
+    assert_eq!(MemorySize::from_bits(1 << 63).natural_alignment(), MemorySize::from_bits(1 << 63));
+    assert_eq!(MemorySize::new().natural_alignment(), MemorySize::from_bits(1 << 63));
+
+    let offset = MemorySize::from_bytes(0x3000);
+    assert!(offset.divides_evenly(offset.natural_alignment()));
+    assert!(!offset.divides_evenly(offset.natural_alignment() << 1));
+}
+
+#[test]
+fn split_at_and_chunks() {
+    let size = MemorySize::from_bits(20);
+
+    assert_eq!(size.split_at(MemorySize::new()), (MemorySize::new(), size));
+    assert_eq!(size.split_at(size), (size, MemorySize::new()));
+
+    let chunks = size.chunks(MemorySize::from_bytes(1));
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks.collect::<Vec<_>>(), [8, 8, 4].map(MemorySize::from_bits));
+    assert_eq!(MemorySize::from_bytes(8).chunks(MemorySize::from_bytes(4)).collect::<Vec<_>>(), [4, 4].map(MemorySize::from_bytes));
+    assert_eq!(MemorySize::new().chunks(MemorySize::from_bytes(4)).count(), 0);
+    assert_eq!(size.chunks(MemorySize::from_bits(u64::MAX)).collect::<Vec<_>>(), [size]);
+}
+
+#[test]
+#[should_panic]
+fn split_at_panic() {
+    MemorySize::from_bytes(1).split_at(MemorySize::from_bytes(2));
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn reference_operators() {
+    let (a, b) = (MemorySize::from_bytes(3), MemorySize::from_bytes(1));
+
+    assert_eq!(&a + &b, MemorySize::from_bytes(4));
+    assert_eq!(a + &b, MemorySize::from_bytes(4));
+    assert_eq!(&a - b, MemorySize::from_bytes(2));
+    assert_eq!(&a - &b, MemorySize::from_bytes(2));
+
+    let mut total = MemorySize::new();
+    total += &a;
+    total -= &b;
+    assert_eq!(total, MemorySize::from_bytes(2));
+
+    let sizes = [a, b, a];
+    assert_eq!(sizes.iter().sum::<MemorySize>(), MemorySize::from_bytes(7));
+    assert_eq!(sizes.iter().fold(MemorySize::new(), |total, size| total + size), MemorySize::from_bytes(7));
+}
+
+#[test]
+fn commutative_scalar_mul() {
+    let element = MemorySize::from_bytes(24);
+
+    assert_eq!(10 * element, element * 10);
+    assert_eq!(1 * element, element);
+}
+
+#[test]
+#[should_panic]
+fn commutative_scalar_mul_panic() {
+    let _ = 2 * MemorySize::from_bits(u64::MAX);
+}
+
+#[test]
+fn const_arithmetic() {
+    use core::cmp::Ordering;
+
+    const TABLE: [MemorySize; 3] = {
+        let header = MemorySize::from_bytes(16);
+        let body = MemorySize::from_bytes(100).const_sub(header);
+        [header, body, header.const_add(body).const_max(MemorySize::from_bytes(128))]
+    };
+    assert_eq!(TABLE, [16, 84, 128].map(MemorySize::from_bytes));
+
+    let (small, large) = (MemorySize::from_bits(1), MemorySize::from_bits(2));
+    assert_eq!(small.const_min(large), small);
+    assert_eq!(large.const_min(small), small);
+    assert_eq!(small.const_max(large), large);
+    assert_eq!(small.const_cmp(large), Ordering::Less);
+    assert_eq!(large.const_cmp(small), Ordering::Greater);
+    assert_eq!(small.const_cmp(small), small.cmp(&small));
+    assert!(small.const_eq(small) && !small.const_eq(large));
+}
+
+#[test]
+fn unit_constructors() {
+    const HEAP: MemorySize = MemorySize::from_mib(256);
+    assert_eq!(HEAP, MemorySize::from_bytes(256 * 1024 * 1024));
+    assert_eq!(MemorySize::from_kib(1), MemorySize::from_bytes(1024));
+    assert_eq!(MemorySize::from_gib(1), MemorySize::from_mib(1024));
+    assert_eq!(MemorySize::from_kb(1), MemorySize::from_bytes(1000));
+    assert_eq!(MemorySize::from_mb(1), MemorySize::from_kb(1000));
+    assert_eq!(MemorySize::from_gb(1), MemorySize::from_mb(1000));
+    assert_eq!(MemorySize::from_gib(0), MemorySize::new());
+    assert_eq!(MemorySize::from_gib(u64::MAX >> 33), MemorySize::from_bytes((u64::MAX >> 33) << 30));
+}
+
+#[test]
+#[should_panic]
+fn unit_constructor_overflow() {
+    let _ = MemorySize::from_gib(1 << 31);
+}
+
+#[test]
+fn unit_and_hardware_constants() {
+    assert_eq!(MemorySize::KIB.size_bytes(), 1024);
+    assert_eq!(MemorySize::MIB, MemorySize::KIB * 1024);
+    assert_eq!(MemorySize::GIB, MemorySize::MIB * 1024);
+    assert_eq!(MemorySize::KB.size_bytes(), 1000);
+    assert_eq!(MemorySize::MB, MemorySize::KB * 1000);
+    assert_eq!(MemorySize::GB, MemorySize::MB * 1000);
+
+    assert_eq!(MemorySize::PAGE_4K, MemorySize::SECTOR_4K);
+    assert_eq!(MemorySize::PAGE_2M, MemorySize::PAGE_4K * 512);
+    assert_eq!(MemorySize::PAGE_1G, MemorySize::PAGE_2M * 512);
+    assert_eq!(MemorySize::from_bytes(100).align_up(MemorySize::CACHE_LINE_64), MemorySize::from_bytes(128));
+}
+
+#[test]
+fn float_unit_constructors() {
+    use crate::MemorySizeError;
+
+    assert_eq!(MemorySize::try_from_gb_f64(1.5), Ok(MemorySize::from_mb(1500)));
+    assert_eq!(MemorySize::try_from_gib_f64(0.0), Ok(MemorySize::new()));
+    assert_eq!(MemorySize::try_from_gib_f64(-0.0), Ok(MemorySize::new()));
+    //a third of a byte rounds to the nearest bit
+    assert_eq!(MemorySize::try_from_bytes_f64(1.0 / 3.0), Ok(MemorySize::from_bits(3)));
+    assert_eq!(MemorySize::try_from_bytes_f64(0.0625), Ok(MemorySize::from_bits(1)));
+
+    assert_eq!(MemorySize::try_from_mib_f64(-0.5), Err(MemorySizeError::Negative));
+    assert_eq!(MemorySize::try_from_kib_f64(f64::NAN), Err(MemorySizeError::InvalidFactor));
+    assert_eq!(MemorySize::try_from_kb_f64(f64::INFINITY), Err(MemorySizeError::InvalidFactor));
+    assert_eq!(MemorySize::try_from_gib_f64(1e10), Err(MemorySizeError::Overflow));
+}
+
+#[test]
+fn parse_rounding_near_u128_max() {
+    use crate::MemorySizeParseError;
+
+    //the mantissa is `u128::MAX`, so rounding by adding half the divisor would overflow
+    let text = "34028236692093846346.3374607431768211455 bit";
+    assert_eq!(text.parse::<MemorySize>(), Err(MemorySizeParseError::Overflow { offset: 0 }));
+    assert_eq!(MemorySize::parse_const(text), None);
+}
+
+#[test]
+fn hash_map_capacity_within_huge_budget() {
+    use crate::estimate;
+
+    let capacity = estimate::hash_map_capacity_within::<u64, u64>(MemorySize::from_bits(u64::MAX));
+    assert!(capacity > 0);
+    assert!(estimate::hash_map_heap_size::<u64, u64>(capacity) <= MemorySize::from_bits(u64::MAX));
+    assert_eq!(estimate::hash_map_capacity_within::<[u8; 1 << 20], ()>(MemorySize::from_bits(u64::MAX)), 7 << 37);
+}
+
+#[test]
+fn parse_unit_suggestions() {
+    use crate::ParseOptions;
+
+    let suggestion = |text: &str| text.parse::<MemorySize>().unwrap_err().suggestion();
+    assert_eq!(suggestion("10 gbi"), Some("Gbit"));
+    assert_eq!(suggestion("10 GiBB"), Some("GiB"));
+    assert_eq!(suggestion("10 megabtyes"), Some("megabytes"));
+    assert_eq!(suggestion("10 kibbibytes"), Some("kibibytes"));
+    assert_eq!(suggestion("10 parsecs"), None);
+    assert_eq!(suggestion("10 x"), None);
+    assert_eq!(suggestion("1.2.3"), None);
+
+    let strict = ParseOptions::new().case_sensitive(true);
+    for unit in crate::parse::UNIT_NAMES {
+        assert!(MemorySize::parse_with(&format!("1 {}", unit), &strict).is_ok(), "parsing {:?}", unit);
+    }
+    let err = MemorySize::parse_with("10 GIb", &strict).unwrap_err();
+    assert_eq!(err.suggestion(), Some("GiB"));
+    assert_eq!(err.to_string(), "unknown unit at byte 3, did you mean \"GiB\"?");
+}
+
+#[test]
+fn parse_c_structs() {
+    use crate::{CStructError, SizeAlign, TargetLayout};
+
+    let source = "
+        #include <stdint.h>
+        /* a packet header */
+        struct header {
+            uint8_t version;
+            unsigned long long sequence; // 8 byte aligned on all presets
+            struct { int16_t x, y; } origin;
+            double weights[2][3];
+        };
+
+        struct packet {
+            struct header header;
+            const char *name;
+            size_t len;
+            uint32_t checksum;
+        };
+    ";
+    let layouts = TargetLayout::WASM32.parse_c_structs(source).unwrap();
+    assert_eq!(layouts.iter().map(|layout| layout.name()).collect::<Vec<_>>(), ["header", "packet"]);
+
+    let header = &layouts[0];
+    let offsets: Vec<_> = header.fields().iter().map(|field| (field.name(), field.offset().size_bytes())).collect();
+    assert_eq!(offsets, [("version", 0), ("sequence", 8), ("origin", 16), ("weights", 24)]);
+    assert_eq!(header.field("weights").unwrap().layout(), SizeAlign::new(MemorySize::from_bytes(48), MemorySize::from_bytes(8)));
+    assert_eq!(header.layout(), SizeAlign::new(MemorySize::from_bytes(72), MemorySize::from_bytes(8)));
+
+    let packet = &layouts[1];
+    assert_eq!(packet.field("name").unwrap().offset(), MemorySize::from_bytes(72));
+    assert_eq!(packet.field("len").unwrap().offset(), MemorySize::from_bytes(76));
+    assert_eq!(packet.layout().size(), MemorySize::from_bytes(88));
+
+    let x86 = TargetLayout::X86_64_SYSV.parse_c_structs(source).unwrap();
+    assert_eq!(x86[1].field("len").unwrap().offset(), MemorySize::from_bytes(80));
+    assert_eq!(x86[1].layout().size(), MemorySize::from_bytes(96));
+
+    let parse = |source| TargetLayout::X86_64_SYSV.parse_c_structs(source);
+    assert_eq!(parse(""), Ok(Vec::new()));
+    assert_eq!(parse("struct a { int x : 3; };"), Err(CStructError::Syntax { offset: 17 }));
+    assert_eq!(parse("struct a { struct b inner; };"), Err(CStructError::UnknownType { offset: 11 }));
+    assert_eq!(parse("struct a { wchar_t c; };"), Err(CStructError::UnknownType { offset: 11 }));
+    assert_eq!(parse("struct a { x; };"), Err(CStructError::UnknownType { offset: 11 }));
+    assert_eq!(parse("struct a { const x; };"), Err(CStructError::UnknownType { offset: 11 }));
+    assert_eq!(parse("struct a { unsigned x; const signed y; };").unwrap()[0].layout().size(), MemorySize::from_bytes(8));
+    assert_eq!(parse("struct a { void *data; }").unwrap_err().offset(), 24);
+    assert_eq!(parse("struct a { uint64_t x[0x4000000000000000]; };"), Err(CStructError::Overflow { offset: 20 }));
+}
+
+#[test]
+fn c_struct_rust_module() {
+    use crate::{RustModuleError, TargetLayout};
+
+    let source = "struct header { uint8_t version; uint64_t sequence; } ; struct empty {};";
+    let layouts = TargetLayout::WASM32.parse_c_structs(source).unwrap();
+    assert_eq!(layouts[0].rust_module().unwrap(), "\
+/// Layout of `struct header`
+pub mod header {
+    pub const SIZE: usize = 16;
+    pub const ALIGN: usize = 8;
+    pub const VERSION_OFFSET: usize = 0;
+    pub const VERSION_SIZE: usize = 1;
+    pub const SEQUENCE_OFFSET: usize = 8;
+    pub const SEQUENCE_SIZE: usize = 8;
+}
+");
+    assert_eq!(layouts[1].rust_module().unwrap(), "/// Layout of `struct empty`\npub mod empty {\n    pub const SIZE: usize = 0;\n    pub const ALIGN: usize = 1;\n}\n");
+
+    //keywords become raw identifiers, and field constants are never keywords
+    let layouts = TargetLayout::WASM32.parse_c_structs("struct type { int match; int self; };").unwrap();
+    assert_eq!(layouts[0].rust_module().unwrap(), "\
+/// Layout of `struct type`
+pub mod r#type {
+    pub const SIZE: usize = 8;
+    pub const ALIGN: usize = 4;
+    pub const MATCH_OFFSET: usize = 0;
+    pub const MATCH_SIZE: usize = 4;
+    pub const SELF_OFFSET: usize = 4;
+    pub const SELF_SIZE: usize = 4;
+}
+");
+
+    let module = |source| TargetLayout::WASM32.parse_c_structs(source).unwrap()[0].rust_module();
+    assert_eq!(module("struct self { int x; };"), Err(RustModuleError::InvalidName { name: "self".to_string() }));
+    assert_eq!(module("struct Self {};"), Err(RustModuleError::InvalidName { name: "Self".to_string() }));
+    assert_eq!(module("struct _ {};"), Err(RustModuleError::InvalidName { name: "_".to_string() }));
+    assert_eq!(module("struct point { int x; int X; };"), Err(RustModuleError::DuplicateConstant { name: "X".to_string() }));
+}
+
+#[test]
+fn c_struct_definition() {
+    use crate::TargetLayout;
+
+    let source = "
+        struct node { struct node *next; };
+        struct header {
+            unsigned char version;
+            const struct node **nodes;
+            struct { int16_t x; uint8_t flags; } origin, corners[2];
+            double weights[2][3];
+            bool valid;
+        };
+    ";
+    let layouts = TargetLayout::X86_64_SYSV.parse_c_structs(source).unwrap();
+    assert_eq!(layouts[1].c_definition(), "\
+struct header {
+    unsigned char version;
+    uint8_t _pad0[7];
+    const struct node **nodes;
+    struct {
+        int16_t x;
+        uint8_t flags;
+        uint8_t _pad1[1];
+    } origin;
+    struct {
+        int16_t x;
+        uint8_t flags;
+        uint8_t _pad2[1];
+    } corners[2];
+    uint8_t _pad3[4];
+    double weights[2][3];
+    bool valid;
+    uint8_t _pad4[7];
+};
+_Static_assert(sizeof(struct header) == 88, \"size of struct header\");
+_Static_assert(_Alignof(struct header) == 8, \"alignment of struct header\");
+");
+
+    //the generated definition describes the same layout
+    let definitions: String = layouts.iter().map(|layout| layout.c_definition()).collect();
+    let reparsed = TargetLayout::X86_64_SYSV.parse_c_structs(&definitions.replace("_Static_assert", "//")).unwrap();
+    assert_eq!(reparsed[1].layout(), layouts[1].layout());
+    assert_eq!(reparsed[1].field("weights").unwrap().offset(), layouts[1].field("weights").unwrap().offset());
+}
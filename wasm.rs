@@ -0,0 +1,53 @@
+use crate::MemorySize;
+
+impl MemorySize {
+    /// The size of a WebAssembly linear memory page (64 KiB)
+    pub const WASM_PAGE: MemorySize = MemorySize::from_bytes(65536);
+
+    /// Constructs a `MemorySize` from a number of WebAssembly pages
+    /// 
+    /// # Panics
+    /// if the size would overflow
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_wasm_pages(2), MemorySize::from_bytes(131072));
+    /// ```
+    pub const fn from_wasm_pages(pages: u64) -> MemorySize {
+        MemorySize::from_bits(pages.checked_mul(Self::WASM_PAGE.size_bits).unwrap())
+    }
+
+    /// Calculates the number of WebAssembly pages needed to hold `&self`
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// assert_eq!(MemorySize::from_bytes(65536).to_wasm_pages_ceil(), 1);
+    /// assert_eq!(MemorySize::from_bytes(65537).to_wasm_pages_ceil(), 2);
+    /// ```
+    pub const fn to_wasm_pages_ceil(&self) -> u64 {
+        self.size_bits.div_ceil(Self::WASM_PAGE.size_bits)
+    }
+
+    /// Calculates the number of pages to pass to `memory.grow` so that a memory of `current_pages` pages can hold `&self`
+    /// 
+    /// Returns 0 if the memory is already large enough
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use memory_size::MemorySize;
+    ///
+    /// let required = MemorySize::from_bytes(200_000);
+    /// assert_eq!(required.wasm_grow_delta(1), 3);
+    /// assert_eq!(required.wasm_grow_delta(10), 0);
+    /// ```
+    pub const fn wasm_grow_delta(&self, current_pages: u64) -> u64 {
+        self.to_wasm_pages_ceil().saturating_sub(current_pages)
+    }
+}